@@ -1,25 +1,30 @@
 #![feature(impl_trait_in_assoc_type)]
 use bevy::prelude::{
-    AlignItems, App, Camera2dBundle, Commands, DefaultPlugins, Display, FlexDirection, NodeBundle,
-    ResMut, Srgba, Startup,
+    AlignItems, App, Camera2dBundle, Commands, DefaultPlugins, Display, FlexDirection, In,
+    NodeBundle, ResMut, Resource, Srgba, Startup, Val,
 };
-use bevy::render::camera::ClearColor;
 use bevy_mod_picking::DefaultPickingPlugins;
 use bevy_mod_stylebuilder::*;
 use bevy_quill::{Cx, Element, QuillPlugin, View, ViewTemplate};
 
 use bevy_quill_demo::{clx, ui::*};
 use bevy_quill_obsidian::controls::Button as ObsidianButton;
+use bevy_quill_obsidian::focus::TabGroup;
 use bevy_quill_obsidian::ObsidianUiPlugin;
 
 fn main() {
     App::new()
-        .insert_resource(ClearColor(Srgba::hex("#171717").unwrap().into()))
+        .init_resource::<Notes>()
+        .init_resource::<Overlays>()
         .add_plugins((
             DefaultPlugins,
             DefaultPickingPlugins,
             QuillPlugin,
-            QuillUiPlugin,
+            QuillUiPlugin {
+                // The root node paints the theme background instead.
+                sync_clear_color: false,
+                persistence: Some(ThemePersistence::in_config_dir("bevy_quill_demo")),
+            },
             // hover + cursor
             ObsidianUiPlugin,
         ))
@@ -32,9 +37,23 @@ fn setup(mut commands: Commands) {
     commands.spawn(
         Element::<NodeBundle>::new()
             .style(|ss: &mut StyleBuilder| {
-                ss.flex_direction(FlexDirection::Column);
+                ss.flex_direction(FlexDirection::Column)
+                    .min_width(Val::Percent(100.))
+                    .min_height(Val::Percent(100.));
             })
-            .children((ThemeSwitcher, ShowcaseView, ClxView))
+            // Tab moves between the controls of the whole page, and the page
+            // background follows the theme.
+            .insert_dyn(|_| (TabGroup::default(), QuillUiRoot), ())
+            .children((
+                ThemeSwitcher,
+                ShowcaseView,
+                FormView,
+                OverlayView,
+                ThemeProvider::new()
+                    .mode(ThemeMode::LIGHT)
+                    .children(SurfacePanel),
+                ClxView,
+            ))
             .to_root(),
     );
 }
@@ -57,20 +76,14 @@ impl ViewTemplate for ThemeSwitcher {
             .children((
                 ObsidianButton::new()
                     .children("Light Theme")
-                    .on_click(cx.create_callback(
-                        |mut theme: ResMut<QuillUiTheme>, mut color: ResMut<ClearColor>| {
-                            theme.mode = ThemeMode::LIGHT;
-                            color.0 = theme.colors.gray[2].into();
-                        },
-                    )),
+                    .on_click(cx.create_callback(|mut theme: ResMut<QuillUiTheme>| {
+                        theme.mode = ThemeMode::LIGHT;
+                    })),
                 ObsidianButton::new()
                     .children("Dark Theme")
-                    .on_click(cx.create_callback(
-                        |mut theme: ResMut<QuillUiTheme>, mut color: ResMut<ClearColor>| {
-                            theme.mode = ThemeMode::DARK;
-                            color.0 = theme.colors.gray[9].into();
-                        },
-                    )),
+                    .on_click(cx.create_callback(|mut theme: ResMut<QuillUiTheme>| {
+                        theme.mode = ThemeMode::DARK;
+                    })),
             ))
     }
 }
@@ -111,6 +124,27 @@ impl ViewTemplate for ShowcaseView {
                         Button::new().children("Gray").color(ButtonColor::Gray),
                         Button::new().children("Black").color(ButtonColor::Black),
                     )),
+                Element::<NodeBundle>::new()
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.display(Display::Flex)
+                            .flex_direction(FlexDirection::Row)
+                            .border(3)
+                            .gap(10)
+                            .padding(3);
+                    })
+                    .children((
+                        Button::new().children("Solid"),
+                        Button::new()
+                            .children("Outline")
+                            .variant(ButtonVariant::Outline),
+                        Button::new().children("Soft").variant(ButtonVariant::Soft),
+                        Button::new()
+                            .children("Ghost")
+                            .variant(ButtonVariant::Ghost),
+                        Button::new().children("Link").variant(ButtonVariant::Link),
+                        Button::new().children("Disabled").disabled(true),
+                        Button::new().children("Loading").loading(true),
+                    )),
                 Element::<NodeBundle>::new()
                     .style(|ss: &mut StyleBuilder| {
                         ss.display(Display::Flex)
@@ -127,6 +161,36 @@ impl ViewTemplate for ShowcaseView {
                         Button::new().children("Size LG").size(ButtonSize::LG),
                         Button::new().children("Size XL").size(ButtonSize::XL),
                     )),
+                Button::new().children("Block").block(true),
+                ButtonGroup::new()
+                    .size(ButtonSize::MD)
+                    .color(ButtonColor::White)
+                    .buttons([
+                        Button::new().children("Left"),
+                        Button::new().children("Center"),
+                        Button::new().children("Right").color(ButtonColor::Indigo),
+                    ]),
+                Element::<NodeBundle>::new()
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.display(Display::Flex)
+                            .flex_direction(FlexDirection::Row)
+                            .gap(10)
+                            .padding(3);
+                    })
+                    .children((
+                        Button::new()
+                            .children("Home")
+                            .variant(ButtonVariant::Link)
+                            .to("/"),
+                        Button::new()
+                            .children("Settings")
+                            .variant(ButtonVariant::Link)
+                            .to("/settings"),
+                        Button::new()
+                            .children("About")
+                            .variant(ButtonVariant::Ghost)
+                            .to("/about"),
+                    )),
                 Element::<NodeBundle>::new().style(|ss: &mut StyleBuilder| {
                     ss.padding_top(10).padding_bottom(10);
                 }),
@@ -141,33 +205,33 @@ impl ViewTemplate for ShowcaseView {
                     })
                     .children((
                         "Primary: ",
-                        Button::new()
-                            .style(move |ss: &mut StyleBuilder| {
-                                ss.background_color(theme.colors.indigo[5])
-                                    .width(32)
-                                    .height(32);
-                            })
-                            .on_click(cx.create_callback(|mut theme: ResMut<QuillUiTheme>| {
-                                theme.colors.primary = theme.colors.indigo;
-                            })),
-                        Button::new()
-                            .style(move |ss: &mut StyleBuilder| {
-                                ss.background_color(theme.colors.blue[5])
-                                    .width(32)
-                                    .height(32);
-                            })
-                            .on_click(cx.create_callback(|mut theme: ResMut<QuillUiTheme>| {
-                                theme.colors.primary = theme.colors.blue;
-                            })),
-                        Button::new()
-                            .style(move |ss: &mut StyleBuilder| {
-                                ss.background_color(theme.colors.green[5])
-                                    .width(32)
-                                    .height(32);
-                            })
-                            .on_click(cx.create_callback(|mut theme: ResMut<QuillUiTheme>| {
-                                theme.colors.primary = theme.colors.green;
-                            })),
+                        ToggleGroup::new()
+                            .selected(theme.colors.primary_name)
+                            .on_change(cx.create_callback(
+                                |name: In<String>, mut theme: ResMut<QuillUiTheme>| {
+                                    theme.colors.set_primary(&name);
+                                },
+                            ))
+                            .children((
+                                Button::new()
+                                    .style(move |ss: &mut StyleBuilder| {
+                                        ss.background_color(theme.colors.indigo[5]);
+                                    })
+                                    .square(true)
+                                    .value("indigo"),
+                                Button::new()
+                                    .style(move |ss: &mut StyleBuilder| {
+                                        ss.background_color(theme.colors.blue[5]);
+                                    })
+                                    .square(true)
+                                    .value("blue"),
+                                Button::new()
+                                    .style(move |ss: &mut StyleBuilder| {
+                                        ss.background_color(theme.colors.green[5]);
+                                    })
+                                    .square(true)
+                                    .value("green"),
+                            )),
                     )),
                 Element::<NodeBundle>::new()
                     .style(|ss: &mut StyleBuilder| {
@@ -180,77 +244,329 @@ impl ViewTemplate for ShowcaseView {
                     })
                     .children((
                         "Gray:    ",
-                        Button::new()
-                            .style(move |ss: &mut StyleBuilder| {
-                                ss.background_color(theme.colors.slate[5])
-                                    .width(32)
-                                    .height(32);
-                            })
-                            .on_click(cx.create_callback(
-                                |mut theme: ResMut<QuillUiTheme>, mut color: ResMut<ClearColor>| {
-                                    theme.colors.gray = theme.colors.slate;
-                                    color.0 = theme.colors.gray
-                                        [if theme.mode == ThemeMode::DARK { 9 } else { 2 }]
-                                    .into();
-                                },
-                            )),
-                        Button::new()
-                            .style(move |ss: &mut StyleBuilder| {
-                                ss.background_color(theme.colors.cool[5])
-                                    .width(32)
-                                    .height(32);
-                            })
-                            .on_click(cx.create_callback(
-                                |mut theme: ResMut<QuillUiTheme>, mut color: ResMut<ClearColor>| {
-                                    theme.colors.gray = theme.colors.cool;
-                                    color.0 = theme.colors.gray
-                                        [if theme.mode == ThemeMode::DARK { 9 } else { 2 }]
-                                    .into();
-                                },
-                            )),
-                        Button::new()
-                            .style(move |ss: &mut StyleBuilder| {
-                                ss.background_color(theme.colors.zinc[5])
-                                    .width(32)
-                                    .height(32);
-                            })
-                            .on_click(cx.create_callback(
-                                |mut theme: ResMut<QuillUiTheme>, mut color: ResMut<ClearColor>| {
-                                    theme.colors.gray = theme.colors.zinc;
-                                    color.0 = theme.colors.gray
-                                        [if theme.mode == ThemeMode::DARK { 9 } else { 2 }]
-                                    .into();
+                        ToggleGroup::new()
+                            .selected(theme.colors.gray_name)
+                            .on_change(cx.create_callback(
+                                |name: In<String>, mut theme: ResMut<QuillUiTheme>| {
+                                    theme.colors.set_gray(&name);
                                 },
+                            ))
+                            .children((
+                                gray_swatch("Slate", "slate", theme.colors.slate[5]),
+                                gray_swatch("Cool", "cool", theme.colors.cool[5]),
+                                gray_swatch("Zinc", "zinc", theme.colors.zinc[5]),
+                                gray_swatch("Neutral", "neutral", theme.colors.neutral[5]),
+                                gray_swatch("Stone", "stone", theme.colors.stone[5]),
                             )),
+                    )),
+            ))
+    }
+}
+
+// Some gray scales look alike (zinc and neutral are identical), so the gray
+// swatches carry their name.
+fn gray_swatch(label: &'static str, name: &'static str, color: Srgba) -> Button {
+    Button::new()
+        .style(move |ss: &mut StyleBuilder| {
+            ss.background_color(color).color(Srgba::WHITE);
+        })
+        .children(label)
+        .value(name)
+}
+
+#[derive(Resource, Default)]
+struct Notes {
+    draft: String,
+    body: String,
+    submitted: Vec<String>,
+    pinned: bool,
+    notify: bool,
+    priority: Option<String>,
+    category: Vec<String>,
+    tags: Vec<String>,
+    // Hours, low and high; `None` until the slider is first moved.
+    estimate: Option<(f32, f32)>,
+}
+
+#[derive(Clone, PartialEq)]
+struct FormView;
+
+impl ViewTemplate for FormView {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let notes = cx.use_resource::<Notes>();
+        let draft = notes.draft.clone();
+        let body = notes.body.clone();
+        let count = notes.submitted.len();
+        let pinned = notes.pinned;
+        let notify = notes.notify;
+        let priority = notes.priority.clone();
+        let category = notes.category.clone();
+        let tags = notes.tags.clone();
+        let estimate = notes.estimate.unwrap_or((2., 8.));
+
+        Element::<NodeBundle>::new()
+            .style(|ss: &mut StyleBuilder| {
+                ss.display(Display::Flex)
+                    .flex_direction(FlexDirection::Column)
+                    .margin(16)
+                    .gap(8)
+                    .max_width(400);
+            })
+            .children((
+                Input::new()
+                    .value(draft)
+                    .placeholder("Write a note...")
+                    .on_change(
+                        cx.create_callback(|value: In<String>, mut notes: ResMut<Notes>| {
+                            notes.draft = value.0;
+                        }),
+                    )
+                    .on_submit(cx.create_callback(
+                        |value: In<String>, mut notes: ResMut<Notes>| {
+                            notes.submitted.push(value.0);
+                            notes.draft.clear();
+                        },
+                    )),
+                Textarea::new()
+                    .value(body)
+                    .placeholder("Details")
+                    .autoresize(6)
+                    .max_length(280)
+                    .show_count(true)
+                    .on_change(cx.create_callback(
+                        |value: In<String>, mut notes: ResMut<Notes>| {
+                            notes.body = value.0;
+                        },
+                    )),
+                Checkbox::new()
+                    .label("Pin note")
+                    .checked(pinned)
+                    .on_change(
+                        cx.create_callback(|value: In<bool>, mut notes: ResMut<Notes>| {
+                            notes.pinned = value.0;
+                        }),
+                    ),
+                Switch::new()
+                    .label("Notify on submit")
+                    .checked(notify)
+                    .color(ButtonColor::Green)
+                    .on_change(
+                        cx.create_callback(|value: In<bool>, mut notes: ResMut<Notes>| {
+                            notes.notify = value.0;
+                        }),
+                    ),
+                RadioGroup::new()
+                    .option("low", "Low priority")
+                    .option("normal", "Normal priority")
+                    .option("high", "High priority")
+                    .selected(priority)
+                    .on_change(cx.create_callback(
+                        |value: In<String>, mut notes: ResMut<Notes>| {
+                            notes.priority = Some(value.0);
+                        },
+                    )),
+                Select::new()
+                    .placeholder("Category")
+                    .option("bug", "Bug")
+                    .option("feature", "Feature")
+                    .option("question", "Question")
+                    .selected(category)
+                    .on_change(cx.create_callback(
+                        |value: In<Vec<String>>, mut notes: ResMut<Notes>| {
+                            notes.category = value.0;
+                        },
+                    )),
+                Select::new()
+                    .placeholder("Tags")
+                    .option("ui", "UI")
+                    .option("audio", "Audio")
+                    .option("physics", "Physics")
+                    .option("rendering", "Rendering")
+                    .option("networking", "Networking")
+                    .multiple(true)
+                    .searchable(true)
+                    .on_change(cx.create_callback(
+                        |value: In<Vec<String>>, mut notes: ResMut<Notes>| {
+                            notes.tags = value.0;
+                        },
+                    ))
+                    .selected(tags),
+                format!("Estimate: {} to {} hours", estimate.0, estimate.1),
+                RangeSlider::new()
+                    .max(40.)
+                    .ticks(8.)
+                    .range(estimate.0, estimate.1)
+                    .on_change(cx.create_callback(
+                        |value: In<(f32, f32)>, mut notes: ResMut<Notes>| {
+                            notes.estimate = Some(value.0);
+                        },
+                    )),
+                format!("Submitted notes: {}", count),
+            ))
+    }
+}
+
+#[derive(Resource, Default)]
+struct Overlays {
+    modal: bool,
+    settings: bool,
+    inventory: bool,
+    popover: bool,
+    menu: bool,
+}
+
+#[derive(Clone, PartialEq)]
+struct OverlayView;
+
+impl ViewTemplate for OverlayView {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let overlays = cx.use_resource::<Overlays>();
+        let modal = overlays.modal;
+        let settings = overlays.settings;
+        let inventory = overlays.inventory;
+        let popover = overlays.popover;
+        let menu = overlays.menu;
+        let open_modal = cx.create_callback(|mut overlays: ResMut<Overlays>| {
+            overlays.modal = true;
+        });
+        let close_modal = cx.create_callback(|mut overlays: ResMut<Overlays>| {
+            overlays.modal = false;
+        });
+
+        let toggle_settings = cx.create_callback(|mut overlays: ResMut<Overlays>| {
+            overlays.settings = !overlays.settings;
+        });
+        let toggle_inventory = cx.create_callback(|mut overlays: ResMut<Overlays>| {
+            overlays.inventory = !overlays.inventory;
+        });
+        let toggle_popover = cx.create_callback(|mut overlays: ResMut<Overlays>| {
+            overlays.popover = !overlays.popover;
+        });
+        let toggle_menu = cx.create_callback(|mut overlays: ResMut<Overlays>| {
+            overlays.menu = !overlays.menu;
+        });
+        let menu_items = vec![
+            MenuItem::new("Settings")
+                .shortcut("Ctrl+,")
+                .on_click(toggle_settings),
+            MenuItem::new("Inventory")
+                .shortcut("I")
+                .on_click(toggle_inventory),
+            MenuItem::new("Share").items(vec![
+                MenuItem::new("Copy link"),
+                MenuItem::new("Export").items(vec![MenuItem::new("PNG"), MenuItem::new("SVG")]),
+            ]),
+            MenuItem::separator(),
+            MenuItem::new("Archive").disabled(true),
+            MenuItem::new("Discard").on_click(open_modal),
+        ];
+
+        Element::<NodeBundle>::new()
+            .style(|ss: &mut StyleBuilder| {
+                ss.display(Display::Flex).margin(16).gap(10);
+            })
+            .children((
+                Tooltip::new()
+                    .text("Ask before discarding")
+                    .shortcut("Ctrl+D")
+                    .arrow(true)
+                    .children(Button::new().children("Open modal").on_click(open_modal)),
+                Button::new().children("Settings").on_click(toggle_settings),
+                Button::new()
+                    .children("Inventory")
+                    .variant(ButtonVariant::Outline)
+                    .on_click(toggle_inventory),
+                Popover::new()
+                    .open(popover)
+                    .on_close(toggle_popover)
+                    .children(
                         Button::new()
-                            .style(move |ss: &mut StyleBuilder| {
-                                ss.background_color(theme.colors.neutral[5])
-                                    .width(32)
-                                    .height(32);
-                            })
-                            .on_click(cx.create_callback(
-                                |mut theme: ResMut<QuillUiTheme>, mut color: ResMut<ClearColor>| {
-                                    theme.colors.gray = theme.colors.neutral;
-                                    color.0 = theme.colors.gray
-                                        [if theme.mode == ThemeMode::DARK { 9 } else { 2 }]
-                                    .into();
-                                },
-                            )),
+                            .children("Rename")
+                            .variant(ButtonVariant::Soft)
+                            .on_click(toggle_popover),
+                    )
+                    .content((
+                        Input::new().placeholder("New name").autofocus(true),
+                        Button::new().children("Save").on_click(toggle_popover),
+                    )),
+                DropdownMenu::new()
+                    .open(menu)
+                    .on_close(toggle_menu)
+                    .items(menu_items.clone())
+                    .children(
                         Button::new()
-                            .style(move |ss: &mut StyleBuilder| {
-                                ss.background_color(theme.colors.stone[5])
-                                    .width(32)
-                                    .height(32);
-                            })
-                            .on_click(cx.create_callback(
-                                |mut theme: ResMut<QuillUiTheme>, mut color: ResMut<ClearColor>| {
-                                    theme.colors.gray = theme.colors.stone;
-                                    color.0 = theme.colors.gray
-                                        [if theme.mode == ThemeMode::DARK { 9 } else { 2 }]
-                                    .into();
-                                },
-                            )),
+                            .children("Actions")
+                            .variant(ButtonVariant::Ghost)
+                            .on_click(toggle_menu),
+                    ),
+                ContextMenu::new()
+                    .items(menu_items)
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.padding((12, 6)).border(1).border_radius(6.0);
+                    })
+                    .children("Right-click here"),
+                Slideover::new()
+                    .open(settings)
+                    .on_close(toggle_settings)
+                    .children((
+                        "Settings",
+                        Switch::new().label("Fullscreen"),
+                        Button::new().children("Close").on_click(toggle_settings),
                     )),
+                Slideover::new()
+                    .open(inventory)
+                    .side(SlideoverSide::Left)
+                    .size(280.)
+                    .modal(false)
+                    .on_close(toggle_inventory)
+                    .children("Inventory is empty"),
+                Modal::new().open(modal).on_close(close_modal).children((
+                    "Discard unsaved changes?",
+                    Element::<NodeBundle>::new()
+                        .style(|ss: &mut StyleBuilder| {
+                            ss.display(Display::Flex).gap(8);
+                        })
+                        .children((
+                            Button::new()
+                                .children("Cancel")
+                                .variant(ButtonVariant::Ghost)
+                                .on_click(close_modal),
+                            Button::new().children("Discard").on_click(close_modal),
+                        )),
+                )),
+            ))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct SurfacePanel;
+
+impl ViewTemplate for SurfacePanel {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let theme = cx.use_theme();
+
+        Element::<NodeBundle>::new()
+            .style_dyn(
+                |surface, ss| {
+                    ss.display(Display::Flex)
+                        .margin(16)
+                        .padding(16)
+                        .gap(10)
+                        .border_radius(8.0)
+                        .background_color(surface);
+                },
+                theme.surface(),
+            )
+            .children((
+                Button::new().children("Primary"),
+                Button::new().children("White").color(ButtonColor::White),
+                Button::new().children("Black").color(ButtonColor::Black),
             ))
     }
 }
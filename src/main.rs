@@ -4,18 +4,21 @@ use bevy::prelude::*;
 use bevy_mod_picking::DefaultPickingPlugins;
 use bevy_mod_stylebuilder::*;
 use bevy_quill::{Cx, Element, For, QuillPlugin, View, ViewTemplate};
-use bevy_quill_obsidian::{
-    controls::{Button, ListView},
-    ObsidianUiPlugin,
-};
+use bevy_quill_demo::ui::{Button, QuillUiPlugin, Slider};
+use bevy_quill_obsidian::{controls::ListView, ObsidianUiPlugin};
 use bevy_quill_obsidian_inspect::{InspectorPlugin, ValueRange};
 use inline_tweak::tweak_fn;
 use reflect::ResourcePropertyInspector;
 mod reflect;
 
+// Inclusive bounds shared by the inspector, the buttons and the slider.
+const COUNTER_MIN: i32 = -100;
+const COUNTER_MAX: i32 = 100;
+
 #[derive(Resource, Default, Clone, Reflect)]
 struct Counter {
-    #[reflect(@ValueRange::<i32>(-100..100))]
+    // `ValueRange` is half-open, so it ends one past `COUNTER_MAX`.
+    #[reflect(@ValueRange::<i32>(COUNTER_MIN..COUNTER_MAX + 1))]
     value: i32,
 }
 
@@ -27,6 +30,10 @@ fn main() {
             DefaultPlugins,
             DefaultPickingPlugins,
             QuillPlugin,
+            QuillUiPlugin {
+                sync_clear_color: false,
+                ..Default::default()
+            },
             ObsidianUiPlugin,
             InspectorPlugin,
         ))
@@ -55,11 +62,15 @@ impl ViewTemplate for CounterView {
     #[tweak_fn]
     fn create(&self, cx: &mut Cx) -> Self::View {
         let inc = cx.create_callback(|mut counter: ResMut<Counter>| {
-            counter.value += 1;
+            counter.value = (counter.value + 1).min(COUNTER_MAX);
         });
 
         let dec = cx.create_callback(|mut counter: ResMut<Counter>| {
-            counter.value -= 1;
+            counter.value = (counter.value - 1).max(COUNTER_MIN);
+        });
+
+        let set = cx.create_callback(|value: In<f32>, mut counter: ResMut<Counter>| {
+            counter.value = (value.0 as i32).clamp(COUNTER_MIN, COUNTER_MAX);
         });
 
         let counter = cx.use_resource::<Counter>();
@@ -75,9 +86,18 @@ impl ViewTemplate for CounterView {
                     .padding(3);
             })
             .children((
-                btn().children("-").on_click(dec),
+                btn().children("-").on_click(dec).repeat(true),
                 format!("The count is: {}", counter.value),
-                btn().children("+").on_click(inc),
+                btn().children("+").on_click(inc).repeat(true),
+                slider()
+                    .min(COUNTER_MIN as f32)
+                    .max(COUNTER_MAX as f32)
+                    .ticks(25.)
+                    .value(counter.value as f32)
+                    .on_change(set)
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.width(200);
+                    }),
             ))
     }
 }
@@ -110,6 +130,10 @@ fn btn() -> Button {
     Button::new()
 }
 
+fn slider() -> Slider {
+    Slider::new()
+}
+
 fn list_view() -> ListView {
     ListView::new()
 }
@@ -1,13 +1,70 @@
 mod button;
+mod button_group;
+mod checkbox;
+mod contrast;
+mod floating;
+mod icon;
+mod input;
+mod menu;
+mod modal;
+mod overlay;
+mod persistence;
+mod popover;
+mod presets;
+mod press;
+mod radio;
+mod router;
+mod select;
+mod sizes;
+mod slideover;
+mod slider;
+mod spinner;
+mod switch;
+#[cfg(test)]
+mod testing;
+mod text_buffer;
+mod textarea;
+mod theme_provider;
+mod toggle_group;
+mod tooltip;
 
 use bevy::color::Srgba;
-use bevy::prelude::App;
-use bevy::prelude::Plugin;
-use bevy::prelude::Resource;
+use bevy::prelude::{
+    resource_changed, Added, App, BackgroundColor, ClearColor, Component, IntoSystemConfigs,
+    Plugin, PostUpdate, PreStartup, Query, Res, ResMut, Resource, Update, With,
+};
+use bevy::transform::TransformSystem;
+use bevy::ui::UiSystem;
 
 pub use button::*;
+pub use button_group::*;
+pub use checkbox::*;
+pub use contrast::*;
+pub use floating::*;
+pub use icon::*;
+pub use input::*;
+pub use menu::*;
+pub use modal::*;
+pub use overlay::*;
+pub use persistence::*;
+pub use popover::*;
+pub use presets::*;
+pub use press::*;
+pub use radio::*;
+pub use router::*;
+pub use select::*;
+pub use sizes::*;
+pub use slideover::*;
+pub use slider::*;
+pub use spinner::*;
+pub use switch::*;
+pub use text_buffer::*;
+pub use textarea::*;
+pub use theme_provider::*;
+pub use toggle_group::*;
+pub use tooltip::*;
 
-#[derive(Default, Clone, PartialEq)]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub enum ThemeMode {
     #[default]
     DARK,
@@ -18,10 +75,20 @@ pub enum ThemeMode {
 pub struct ColorScales {
     pub primary: [Srgba; 11],
     pub gray: [Srgba; 11],
+    // Names of the scales `primary` and `gray` were picked from, kept by
+    // `set_primary` and `set_gray`. Some scales share colors (zinc and
+    // neutral), so the name can't be recovered from the colors alone.
+    pub primary_name: Option<&'static str>,
+    pub gray_name: Option<&'static str>,
 
     pub green: [Srgba; 11],
+    pub teal: [Srgba; 11],
+    pub sky: [Srgba; 11],
     pub indigo: [Srgba; 11],
     pub blue: [Srgba; 11],
+    pub yellow: [Srgba; 11],
+    pub orange: [Srgba; 11],
+    pub rose: [Srgba; 11],
 
     pub slate: [Srgba; 11],
     pub cool: [Srgba; 11],
@@ -33,6 +100,61 @@ pub struct ColorScales {
     pub black: Srgba,
 }
 
+impl ColorScales {
+    pub fn by_name(&self, name: &str) -> Option<[Srgba; 11]> {
+        match name {
+            "green" => Some(self.green),
+            "teal" => Some(self.teal),
+            "sky" => Some(self.sky),
+            "indigo" => Some(self.indigo),
+            "blue" => Some(self.blue),
+            "yellow" => Some(self.yellow),
+            "orange" => Some(self.orange),
+            "rose" => Some(self.rose),
+            "slate" => Some(self.slate),
+            "cool" => Some(self.cool),
+            "zinc" => Some(self.zinc),
+            "neutral" => Some(self.neutral),
+            "stone" => Some(self.stone),
+            _ => None,
+        }
+    }
+
+    // Makes the scale called `name` the primary scale. Returns false, leaving
+    // the theme as is, when there is no such scale.
+    pub fn set_primary(&mut self, name: &str) -> bool {
+        match (scale_name(name), self.by_name(name)) {
+            (Some(name), Some(scale)) => {
+                self.primary = scale;
+                self.primary_name = Some(name);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Like `set_primary`, for the gray scale.
+    pub fn set_gray(&mut self, name: &str) -> bool {
+        match (scale_name(name), self.by_name(name)) {
+            (Some(name), Some(scale)) => {
+                self.gray = scale;
+                self.gray_name = Some(name);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+const SCALE_NAMES: [&str; 13] = [
+    "green", "teal", "sky", "indigo", "blue", "yellow", "orange", "rose", "slate", "cool", "zinc",
+    "neutral", "stone",
+];
+
+fn scale_name(name: &str) -> Option<&'static str> {
+    SCALE_NAMES.into_iter().find(|n| *n == name)
+}
+
 fn into_colors(colors: [&str; 11]) -> [Srgba; 11] {
     [
         Srgba::hex(colors[0]).unwrap(),
@@ -49,10 +171,47 @@ fn into_colors(colors: [&str; 11]) -> [Srgba; 11] {
     ]
 }
 
+#[derive(Default, Clone, PartialEq)]
+pub struct ThemeTokens {
+    pub background: Option<Srgba>,
+    pub surface: Option<Srgba>,
+    pub text: Option<Srgba>,
+    pub disabled_opacity: Option<f32>,
+}
+
 #[derive(Resource, Clone, PartialEq)]
 pub struct QuillUiTheme {
     pub mode: ThemeMode,
     pub colors: ColorScales,
+    pub tokens: ThemeTokens,
+    pub sizes: SizeScale,
+}
+
+impl QuillUiTheme {
+    pub fn background(&self) -> Srgba {
+        self.tokens.background.unwrap_or(match self.mode {
+            ThemeMode::DARK => self.colors.gray[9],
+            ThemeMode::LIGHT => self.colors.gray[2],
+        })
+    }
+
+    pub fn surface(&self) -> Srgba {
+        self.tokens.surface.unwrap_or(match self.mode {
+            ThemeMode::DARK => self.colors.gray[8],
+            ThemeMode::LIGHT => self.colors.white,
+        })
+    }
+
+    pub fn disabled_opacity(&self) -> f32 {
+        self.tokens.disabled_opacity.unwrap_or(0.5)
+    }
+
+    pub fn text(&self) -> Srgba {
+        self.tokens.text.unwrap_or(match self.mode {
+            ThemeMode::DARK => self.colors.white,
+            ThemeMode::LIGHT => self.colors.gray[9],
+        })
+    }
 }
 
 impl Default for QuillUiTheme {
@@ -72,8 +231,18 @@ impl Default for QuillUiTheme {
             colors: ColorScales {
                 primary: green,
                 gray: zinc,
+                primary_name: Some("green"),
+                gray_name: Some("zinc"),
 
                 green,
+                teal: into_colors([
+                    "#f0fdfa", "#ccfbf1", "#99f6e4", "#5eead4", "#2dd4bf", "#14b8a6", "#0d9488",
+                    "#0f766e", "#115e59", "#134e4a", "#042f2e",
+                ]),
+                sky: into_colors([
+                    "#f0f9ff", "#e0f2fe", "#bae6fd", "#7dd3fc", "#38bdf8", "#0ea5e9", "#0284c7",
+                    "#0369a1", "#075985", "#0c4a6e", "#082f49",
+                ]),
                 indigo: into_colors([
                     "#eef2ff", "#e0e7ff", "#c7d2fe", "#a5b4fc", "#818cf8", "#6366f1", "#4f46e5",
                     "#4338ca", "#3730a3", "#312e81", "#1e1b4b",
@@ -82,6 +251,18 @@ impl Default for QuillUiTheme {
                     "#eff6ff", "#dbeafe", "#bfdbfe", "#93c5fd", "#60a5fa", "#3b82f6", "#2563eb",
                     "#1d4ed8", "#1e40af", "#1e3a8a", "#172554",
                 ]),
+                yellow: into_colors([
+                    "#fefce8", "#fef9c3", "#fef08a", "#fde047", "#facc15", "#eab308", "#ca8a04",
+                    "#a16207", "#854d0e", "#713f12", "#422006",
+                ]),
+                orange: into_colors([
+                    "#fff7ed", "#ffedd5", "#fed7aa", "#fdba74", "#fb923c", "#f97316", "#ea580c",
+                    "#c2410c", "#9a3412", "#7c2d12", "#431407",
+                ]),
+                rose: into_colors([
+                    "#fff1f2", "#ffe4e6", "#fecdd3", "#fda4af", "#fb7185", "#f43f5e", "#e11d48",
+                    "#be123c", "#9f1239", "#881337", "#4c0519",
+                ]),
 
                 zinc,
                 slate: into_colors([
@@ -107,14 +288,133 @@ impl Default for QuillUiTheme {
                 white: Srgba::WHITE,
                 black: Srgba::BLACK,
             },
+            tokens: ThemeTokens::default(),
+            sizes: SizeScale::default(),
         }
     }
 }
 
-pub struct QuillUiPlugin;
+// Marks a root node whose background follows the theme background token.
+#[derive(Component, Default, Clone, Copy)]
+pub struct QuillUiRoot;
+
+pub struct QuillUiPlugin {
+    // Keep `ClearColor` in sync with the theme background. Disable this if the
+    // app manages its own clear color.
+    pub sync_clear_color: bool,
+    // Save the chosen mode and color scales and restore them on startup.
+    pub persistence: Option<ThemePersistence>,
+}
+
+impl Default for QuillUiPlugin {
+    fn default() -> Self {
+        Self {
+            sync_clear_color: true,
+            persistence: None,
+        }
+    }
+}
 
 impl Plugin for QuillUiPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<QuillUiTheme>();
+        app.init_resource::<QuillUiTheme>()
+            .init_resource::<ActiveRoute>()
+            .init_resource::<InternalClipboard>()
+            .init_resource::<FocusTraps>()
+            .add_systems(
+                Update,
+                (
+                    sync_root_background,
+                    rotate_spinners,
+                    update_pressed,
+                    edit_text_inputs,
+                    typeahead_selects,
+                    animate_switch_thumbs,
+                    dismiss_floating,
+                    autofocus_floating,
+                    animate_overlay_transitions,
+                    update_tooltips,
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    attach_overlay_layers.before(UiSystem::Layout),
+                    // These read this frame's layout: node sizes, and
+                    // positions once transforms have been propagated.
+                    position_floating
+                        .after(UiSystem::Layout)
+                        .after(TransformSystem::TransformPropagate),
+                    measure_textareas
+                        .after(UiSystem::Layout)
+                        .after(TransformSystem::TransformPropagate),
+                    trap_focus,
+                ),
+            );
+
+        if self.sync_clear_color {
+            app.init_resource::<ClearColor>().add_systems(
+                Update,
+                sync_clear_color.run_if(resource_changed::<QuillUiTheme>),
+            );
+        }
+
+        if let Some(persistence) = &self.persistence {
+            app.insert_resource(persistence.clone())
+                .init_resource::<ThemeSaveState>()
+                .add_systems(PreStartup, load_theme_settings)
+                .add_systems(Update, save_theme_settings);
+        }
+    }
+}
+
+fn sync_clear_color(theme: Res<QuillUiTheme>, mut clear_color: ResMut<ClearColor>) {
+    clear_color.0 = theme.background().into();
+}
+
+fn sync_root_background(
+    theme: Res<QuillUiTheme>,
+    mut roots: Query<&mut BackgroundColor, With<QuillUiRoot>>,
+    added: Query<(), Added<QuillUiRoot>>,
+) {
+    if !theme.is_changed() && added.is_empty() {
+        return;
+    }
+
+    for mut bg in roots.iter_mut() {
+        bg.0 = theme.background().into();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::{Color, NodeBundle};
+
+    use super::*;
+
+    #[test]
+    fn root_background_follows_the_theme() {
+        let mut app = App::new();
+        app.init_resource::<QuillUiTheme>()
+            .add_systems(Update, sync_root_background);
+        let root = app
+            .world_mut()
+            .spawn((NodeBundle::default(), QuillUiRoot))
+            .id();
+        let other = app.world_mut().spawn(NodeBundle::default()).id();
+        let background = |app: &App, entity| app.world().get::<BackgroundColor>(entity).unwrap().0;
+
+        app.update();
+        let dark = app.world().resource::<QuillUiTheme>().background();
+        assert_eq!(background(&app, root), Color::from(dark));
+
+        app.world_mut().resource_mut::<QuillUiTheme>().mode = ThemeMode::LIGHT;
+        app.update();
+        let light = app.world().resource::<QuillUiTheme>().background();
+        assert_ne!(light, dark);
+        assert_eq!(background(&app, root), Color::from(light));
+
+        // Nodes without the marker are left alone.
+        assert_eq!(background(&app, other), Color::NONE);
     }
 }
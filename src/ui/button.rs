@@ -1,25 +1,39 @@
-use super::QuillUiTheme;
+use std::time::Duration;
+
+use super::{
+    click_to_focus, readable_shade, ActiveRoute, ButtonGroupContext, ButtonSegment, Icon, IconView,
+    LastClick, Pressable, Pressed, QuillUiTheme, Spinner, SuppressClick, ToggleGroupContext,
+    UseIsPressed, UseTheme, DOUBLE_CLICK_INTERVAL,
+};
 use crate::ui::ThemeMode;
 use bevy::{
     a11y::{
-        accesskit::{NodeBuilder, Role},
-        AccessibilityNode, Focus,
+        accesskit::{NodeBuilder, Role, Toggled},
+        AccessibilityNode,
     },
-    color::{Color, Srgba},
-    prelude::{NodeBundle, World},
+    color::{Alpha, Color, Srgba},
+    prelude::{DetectChangesMut, KeyCode, NodeBundle, World},
+    time::Time,
     ui::Val,
     window::CursorIcon,
 };
-use bevy_mod_picking::prelude::{Click, ListenerInput, On, Pointer};
+use bevy_mod_picking::prelude::{Down, ListenerInput, On, Out, Pointer, PointerButton, Up};
 
 use bevy_mod_stylebuilder::{
     StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
-    StyleBuilderFont, StyleBuilderLayout, StyleHandle, StyleTuple,
+    StyleBuilderFont, StyleBuilderLayout, StyleBuilderOutline, StyleHandle, StyleTuple,
+};
+use bevy_quill::{
+    Callback, Cond, Element, IntoViewChild, RunCallback, View, ViewChild, ViewTemplate,
+};
+use bevy_quill_obsidian::{
+    controls::{Disabled, IsDisabled},
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
 };
-use bevy_quill::{Callback, Element, IntoViewChild, RunCallback, View, ViewChild, ViewTemplate};
-use bevy_quill_obsidian::{controls::IsDisabled, cursor::StyleBuilderCursor, hooks::UseIsHover};
 
-#[derive(Default, Clone, Copy, PartialEq)]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub enum ButtonVariant {
     Link,
     #[default]
@@ -29,7 +43,7 @@ pub enum ButtonVariant {
     Ghost,
 }
 
-#[derive(Default, Clone, Copy, PartialEq)]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub enum ButtonColor {
     #[default]
     Primary,
@@ -55,13 +69,36 @@ pub enum ButtonSize {
 #[derive(Default, Clone, PartialEq)]
 pub struct Button {
     pub children: ViewChild,
-    pub color: ButtonColor,
+    // `None` falls back to the enclosing `ButtonGroup`, then the default.
+    pub color: Option<ButtonColor>,
     pub variant: ButtonVariant,
-    pub size: ButtonSize,
+    pub size: Option<ButtonSize>,
     pub style: StyleHandle,
     pub block: bool,
+    pub square: bool,
+    pub disabled: bool,
+    pub loading: bool,
+    pub tab_index: i32,
+    pub leading_icon: Option<Icon>,
+    pub trailing_icon: Option<Icon>,
+    // Route to navigate to on click, see `ActiveRoute`.
+    pub to: Option<String>,
+    // `Some` turns the button into a toggle.
+    pub checked: Option<bool>,
+    // Identifies the button inside a `ToggleGroup`.
+    pub value: Option<String>,
+    // Set by `ButtonGroup` for each of its buttons.
+    pub segment: Option<ButtonSegment>,
 
     pub on_click: Option<Callback>,
+    pub on_press: Option<Callback>,
+    pub on_release: Option<Callback>,
+    pub on_long_press: Option<Callback>,
+    pub on_double_click: Option<Callback>,
+    pub long_press_duration: Option<Duration>,
+    // Keep firing `on_click` while the button is held.
+    pub repeat: bool,
+    pub on_change: Option<Callback<bool>>,
 }
 
 impl Button {
@@ -75,12 +112,77 @@ impl Button {
     }
 
     pub fn color(mut self, color: ButtonColor) -> Self {
-        self.color = color;
+        self.color = Some(color);
+        self
+    }
+
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
         self
     }
 
     pub fn size(mut self, size: ButtonSize) -> Self {
-        self.size = size;
+        self.size = Some(size);
+        self
+    }
+
+    pub fn block(mut self, block: bool) -> Self {
+        self.block = block;
+        self
+    }
+
+    pub fn square(mut self, square: bool) -> Self {
+        self.square = square;
+        self
+    }
+
+    pub fn leading_icon(mut self, icon: Icon) -> Self {
+        self.leading_icon = Some(icon);
+        self
+    }
+
+    pub fn trailing_icon(mut self, icon: Icon) -> Self {
+        self.trailing_icon = Some(icon);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    pub fn to(mut self, route: impl Into<String>) -> Self {
+        self.to = Some(route.into());
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn segment(mut self, segment: ButtonSegment) -> Self {
+        self.segment = Some(segment);
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<bool>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
         self
     }
 
@@ -89,6 +191,36 @@ impl Button {
         self
     }
 
+    pub fn on_press(mut self, callback: Callback) -> Self {
+        self.on_press = Some(callback);
+        self
+    }
+
+    pub fn on_release(mut self, callback: Callback) -> Self {
+        self.on_release = Some(callback);
+        self
+    }
+
+    pub fn on_long_press(mut self, callback: Callback) -> Self {
+        self.on_long_press = Some(callback);
+        self
+    }
+
+    pub fn long_press_duration(mut self, duration: Duration) -> Self {
+        self.long_press_duration = Some(duration);
+        self
+    }
+
+    pub fn on_double_click(mut self, callback: Callback) -> Self {
+        self.on_double_click = Some(callback);
+        self
+    }
+
+    pub fn repeat(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
     pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
         self.style = style.into_handle();
         self
@@ -100,104 +232,553 @@ impl ViewTemplate for Button {
 
     fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
         let id = cx.create_entity();
-        let color = self.color;
-        let hovering = cx.is_hovered(id);
-        let theme = cx.use_resource::<QuillUiTheme>().clone();
+        let group = cx
+            .use_inherited_component::<ButtonGroupContext>()
+            .copied()
+            .unwrap_or_default();
+        let color = self.color.or(group.color).unwrap_or_default();
+        let size = self.size.or(group.size).unwrap_or_default();
+        let variant = self.variant;
+        let disabled = self.disabled;
+        let loading = self.loading;
+        let pressed = cx.is_pressed(id);
+        let hovering = (cx.is_hovered(id) || pressed) && !disabled;
+        let focused = cx.is_focus_visible(id);
+        let theme = cx.use_theme();
+        let route = cx.use_resource::<ActiveRoute>();
+        let active = self.to.as_deref().map(|to| route.matches(to));
+        // Inside a `ToggleGroup` the group owns the checked state.
+        let toggle_group = cx.use_inherited_component::<ToggleGroupContext>().cloned();
+        let (checked, on_change, select) = match (toggle_group, &self.value) {
+            (Some(group), Some(value)) => (
+                Some(group.selected.as_ref() == Some(value)),
+                None,
+                group.on_change.map(|on_change| (on_change, value.clone())),
+            ),
+            _ => (self.checked, self.on_change, None),
+        };
         let on_click = self.on_click;
+        let on_press = self.on_press;
+        let on_release = self.on_release;
+        let on_double_click = self.on_double_click;
+        let icon_color = button_colors(color, variant, theme.clone(), hovering).text;
+        let icon_size = theme.size(size).icon_size;
         Element::<NodeBundle>::for_entity(id)
             .named("Button")
             .style((
                 |ss: &mut StyleBuilder| {
-                    ss.cursor(CursorIcon::Pointer);
-
-                    // block: w-full flex justify-center items-center
                     ss.display(bevy::ui::Display::Flex)
                         .flex_shrink(0.)
-                        .justify_items(bevy::ui::JustifyItems::Center)
-                        // .width(Val::Px(100.))
                         .align_items(bevy::ui::AlignItems::Center);
 
-                    // inline: inline-flex items-center
-
-                    // rounded-md
-                    ss.border_radius(6.0);
-
                     ss.border(1).border_color(Color::BLACK);
                 },
                 self.style.clone(),
             ))
             .style_dyn(
-                |(hovering, color, theme, style), sb| {
-                    sb.background_color(button_bg_color(color, theme.clone(), hovering));
-                    sb.color(button_color(color, theme.clone(), hovering));
+                |block, sb| {
+                    if block {
+                        // block: w-full flex justify-center items-center
+                        sb.width(Val::Percent(100.))
+                            .justify_content(bevy::ui::JustifyContent::Center);
+                    } else {
+                        // inline: inline-flex items-center
+                        sb.width(Val::Auto)
+                            .justify_content(bevy::ui::JustifyContent::FlexStart);
+                    }
+                },
+                self.block,
+            )
+            .style_dyn(
+                |(hovering, active, checked, disabled, color, variant, segment, theme, style),
+                 sb| {
+                    // active routes keep their hover treatment
+                    let mut colors = if checked == Some(true) {
+                        button_checked_colors(color, variant, theme.clone(), hovering)
+                    } else {
+                        button_colors(
+                            color,
+                            variant,
+                            theme.clone(),
+                            hovering || active == Some(true),
+                        )
+                    };
+                    if let (ButtonVariant::Link, Some(active)) = (variant, active) {
+                        colors.text = link_route_color(&theme, active, hovering);
+                    }
+                    if disabled {
+                        colors = colors.dimmed(theme.disabled_opacity());
+                        sb.cursor(CursorIcon::NotAllowed);
+                    } else {
+                        sb.cursor(CursorIcon::Pointer);
+                    }
 
-                    if color == ButtonColor::White || color == ButtonColor::Gray {
-                        sb.border(1).border_color(
-                            theme.colors.gray[if theme.mode == ThemeMode::DARK { 7 } else { 3 }],
-                        );
+                    sb.background_color(colors.bg);
+                    sb.color(colors.text);
+
+                    let border_width = if let Some(border) = colors.border {
+                        sb.border(1).border_color(border);
+                        1.
                     } else {
                         sb.border(0);
+                        0.
+                    };
+                    if let Some(segment) = segment {
+                        let (left, top) = segment.margin(border_width);
+                        sb.margin_left(left).margin_top(top);
                     }
 
                     style.apply(sb);
                 },
-                (hovering, color, theme, self.style.clone()),
+                (
+                    hovering,
+                    active,
+                    checked,
+                    disabled,
+                    color,
+                    variant,
+                    self.segment,
+                    theme.clone(),
+                    self.style.clone(),
+                ),
             )
             .style_dyn(
-                |size, sb| match size {
-                    ButtonSize::XS => {
-                        sb.column_gap(6.0).font_size(12).padding((10, 6));
-                    }
-                    ButtonSize::SM => {
-                        sb.column_gap(6.0).font_size(16).padding((10, 6));
-                    }
-                    ButtonSize::MD => {
-                        sb.column_gap(8.0).font_size(16).padding((12, 8));
+                |(tokens, square, segment), sb| {
+                    sb.column_gap(tokens.gap).font_size(tokens.font_size);
+
+                    match segment {
+                        Some(segment) => {
+                            sb.border_radius(segment.border_radius(tokens.radius));
+                        }
+                        None => {
+                            sb.border_radius(tokens.radius);
+                        }
                     }
-                    ButtonSize::LG => {
-                        sb.column_gap(10.0).font_size(16).padding((14, 10));
+
+                    if square {
+                        // sized so an empty button stays square
+                        let extent = tokens.icon_size + tokens.padding_y * 2.0;
+                        sb.padding(tokens.padding_y)
+                            .min_width(extent)
+                            .min_height(extent);
+                    } else {
+                        sb.padding((tokens.padding_x, tokens.padding_y));
                     }
-                    ButtonSize::XL => {
-                        sb.column_gap(10.0).font_size(16).padding((14, 10));
+                },
+                (theme.size(size), self.square, self.segment),
+            )
+            .style_dyn(
+                |(focused, ring), sb| {
+                    // focus-visible:outline-2 focus-visible:outline-offset-2
+                    if focused {
+                        sb.outline_color(ring).outline_width(2).outline_offset(2);
+                    } else {
+                        sb.outline_color(Color::NONE);
                     }
                 },
-                self.size,
+                (focused, button_ring_color(color, theme.clone())),
             )
+            .insert_if(disabled, || Disabled)
             .insert_dyn(
-                move |_| {
+                move |(disabled, loading, tab_index, to, checked, on_change, select)| {
+                    let mut node = NodeBuilder::new(if to.is_some() {
+                        Role::Link
+                    } else {
+                        Role::Button
+                    });
+                    if disabled {
+                        node.set_disabled();
+                    }
+                    if loading {
+                        node.set_busy();
+                    }
+                    if let Some(checked) = checked {
+                        node.set_toggled(if checked {
+                            Toggled::True
+                        } else {
+                            Toggled::False
+                        });
+                    }
+
+                    let navigate = move |world: &mut World| {
+                        if let Some(to) = &to {
+                            world
+                                .resource_mut::<ActiveRoute>()
+                                .set_if_neq(ActiveRoute::new(to));
+                        }
+                    };
+                    let toggle = move |world: &mut World| {
+                        if let (Some(checked), Some(on_change)) = (checked, on_change) {
+                            world.run_callback(on_change, !checked);
+                        }
+                        if let Some((on_select, value)) = &select {
+                            world.run_callback(*on_select, value.clone());
+                        }
+                    };
+                    let navigate_click = navigate.clone();
+                    let toggle_click = toggle.clone();
+
                     (
-                        AccessibilityNode::from(NodeBuilder::new(Role::Button)),
-                        On::<Pointer<Click>>::run(move |world: &mut World| {
-                            let mut focus = world.get_resource_mut::<Focus>().unwrap();
-                            focus.0 = Some(id);
+                        AccessibilityNode::from(node),
+                        TabIndex(if disabled { -1 } else { tab_index }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
                             if !world.is_disabled(id) {
                                 let mut event = world
-                                    .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                    .get_resource_mut::<ListenerInput<KeyPressEvent>>()
                                     .unwrap();
-                                event.stop_propagation();
-                                if let Some(on_click) = on_click {
-                                    world.run_callback(on_click, ());
+                                if !event.repeat
+                                    && (event.key_code == KeyCode::Enter
+                                        || event.key_code == KeyCode::Space)
+                                {
+                                    event.stop_propagation();
+                                    if loading {
+                                        return;
+                                    }
+                                    navigate(world);
+                                    toggle(world);
+                                    if let Some(on_click) = on_click {
+                                        world.run_callback(on_click, ());
+                                    }
+                                }
+                            }
+                        }),
+                        click_to_focus(id, move |world: &mut World| {
+                            if loading {
+                                return;
+                            }
+                            // swallowed by a long press or auto-repeat
+                            if world.entity_mut(id).take::<SuppressClick>().is_some() {
+                                return;
+                            }
+                            navigate_click(world);
+                            toggle_click(world);
+                            if let Some(on_click) = on_click {
+                                world.run_callback(on_click, ());
+                            }
+
+                            let now = world.resource::<Time>().elapsed();
+                            let previous = world.get::<LastClick>(id).map(|click| click.0);
+                            world.entity_mut(id).insert(LastClick(now));
+                            if let (Some(previous), Some(on_double_click)) =
+                                (previous, on_double_click)
+                            {
+                                if now.saturating_sub(previous) <= DOUBLE_CLICK_INTERVAL {
+                                    world.entity_mut(id).remove::<LastClick>();
+                                    world.run_callback(on_double_click, ());
                                 }
                             }
                         }),
                     )
                 },
-                (),
+                (
+                    disabled,
+                    loading,
+                    self.tab_index,
+                    self.to.clone(),
+                    checked,
+                    on_change,
+                    select,
+                ),
+            )
+            .insert_dyn(
+                move |(loading, pressable)| {
+                    // Ends a press, reporting whether the click should be swallowed.
+                    let release = move |world: &mut World| {
+                        let Some(pressed) = world.entity_mut(id).take::<Pressed>() else {
+                            return;
+                        };
+                        if pressed.consumed() {
+                            world.entity_mut(id).insert(SuppressClick);
+                        }
+                        if let Some(on_release) = on_release {
+                            world.run_callback(on_release, ());
+                        }
+                    };
+
+                    (
+                        pressable,
+                        On::<Pointer<Down>>::run(move |world: &mut World| {
+                            let event = world.resource::<ListenerInput<Pointer<Down>>>();
+                            if event.button != PointerButton::Primary
+                                || loading
+                                || world.is_disabled(id)
+                            {
+                                return;
+                            }
+                            let now = world.resource::<Time>().elapsed();
+                            let mut entity = world.entity_mut(id);
+                            entity.remove::<SuppressClick>();
+                            entity.insert(Pressed::new(now));
+                            if let Some(on_press) = on_press {
+                                world.run_callback(on_press, ());
+                            }
+                        }),
+                        On::<Pointer<Up>>::run(release),
+                        On::<Pointer<Out>>::run(release),
+                    )
+                },
+                (
+                    loading,
+                    Pressable {
+                        on_click,
+                        on_long_press: self.on_long_press,
+                        long_press_duration: self
+                            .long_press_duration
+                            .unwrap_or(Duration::from_millis(500)),
+                        repeat: self.repeat,
+                        loading,
+                    },
+                ),
             )
-            .children(self.children.clone())
+            .children((
+                Cond::new(
+                    loading,
+                    Spinner::new().size(icon_size).color(icon_color),
+                    IconView::new(self.leading_icon.clone(), icon_size, icon_color),
+                ),
+                self.children.clone(),
+                IconView::new(self.trailing_icon.clone(), icon_size, icon_color),
+            ))
     }
 }
 
-fn button_bg_color(color: ButtonColor, theme: QuillUiTheme, is_hovering: bool) -> Srgba {
-    let mode = theme.mode;
-    let index = if is_hovering {
-        5
+// The selected look of a toggle, one step "up" from its variant.
+fn button_checked_colors(
+    color: ButtonColor,
+    variant: ButtonVariant,
+    theme: QuillUiTheme,
+    is_hovering: bool,
+) -> ButtonColors {
+    match variant {
+        ButtonVariant::Solid => ButtonColors {
+            border: Some(button_ring_color(color, theme.clone())),
+            ..button_colors(color, ButtonVariant::Solid, theme, true)
+        },
+        ButtonVariant::Outline => {
+            let colors = button_colors(color, ButtonVariant::Soft, theme, is_hovering);
+            ButtonColors {
+                border: Some(colors.text),
+                ..colors
+            }
+        }
+        ButtonVariant::Soft => button_colors(color, ButtonVariant::Solid, theme, is_hovering),
+        ButtonVariant::Ghost => button_colors(color, ButtonVariant::Soft, theme, is_hovering),
+        ButtonVariant::Link => button_colors(color, ButtonVariant::Link, theme, true),
+    }
+}
+
+// text-primary when active, text-gray-500 dark:text-gray-400 hover:text-gray-700 otherwise
+fn link_route_color(theme: &QuillUiTheme, active: bool, is_hovering: bool) -> Srgba {
+    let dark = theme.mode == ThemeMode::DARK;
+    if active {
+        readable_shade(
+            &theme.colors.primary,
+            if dark { 4 } else { 7 },
+            theme.background(),
+        )
+    } else if is_hovering {
+        theme.colors.gray[if dark { 2 } else { 7 }]
     } else {
-        if mode == ThemeMode::DARK {
-            4
-        } else {
-            6
+        theme.colors.gray[if dark { 4 } else { 5 }]
+    }
+}
+
+// focus-visible:outline-{color}-500 dark:focus-visible:outline-{color}-400
+pub(crate) fn button_ring_color(color: ButtonColor, theme: QuillUiTheme) -> Srgba {
+    let index = if theme.mode == ThemeMode::DARK { 4 } else { 5 };
+    match color {
+        ButtonColor::Primary | ButtonColor::White | ButtonColor::Black | ButtonColor::Gray => {
+            theme.colors.primary[index]
         }
+        ButtonColor::Indigo => theme.colors.indigo[index],
+        ButtonColor::Blue => theme.colors.blue[index],
+        ButtonColor::Green => theme.colors.green[index],
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ButtonColors {
+    pub bg: Srgba,
+    pub text: Srgba,
+    pub border: Option<Srgba>,
+}
+
+impl ButtonColors {
+    pub fn dimmed(self, opacity: f32) -> Self {
+        let dim = |c: Srgba| c.with_alpha(c.alpha * opacity);
+        Self {
+            bg: dim(self.bg),
+            text: dim(self.text),
+            border: self.border.map(dim),
+        }
+    }
+}
+
+pub(crate) fn button_colors(
+    color: ButtonColor,
+    variant: ButtonVariant,
+    theme: QuillUiTheme,
+    is_hovering: bool,
+) -> ButtonColors {
+    let dark = theme.mode == ThemeMode::DARK;
+    let scale = match color {
+        ButtonColor::Primary => theme.colors.primary,
+        ButtonColor::Indigo => theme.colors.indigo,
+        ButtonColor::Blue => theme.colors.blue,
+        ButtonColor::Green => theme.colors.green,
+        ButtonColor::White | ButtonColor::Black | ButtonColor::Gray => theme.colors.gray,
+    };
+
+    let page = theme.background();
+    // transparent backgrounds show the page behind them
+    let on = |bg: Srgba| if bg.alpha == 0.0 { page } else { bg };
+    let neutral = neutral_tokens(color, &theme);
+
+    // text-{color}-700 dark:text-{color}-400, stepped further until it is readable
+    let ink = |bg: Srgba| match neutral {
+        Some(neutral) => neutral.ink,
+        None => readable_shade(&scale, if dark { 4 } else { 7 }, bg),
+    };
+
+    // hover:bg-{color}-50 dark:hover:bg-{color}-950
+    let tint = match (is_hovering, neutral) {
+        (false, _) => Srgba::NONE,
+        (true, Some(neutral)) => neutral.tint,
+        (true, None) => scale[if dark { 10 } else { 0 }],
+    };
+
+    match variant {
+        ButtonVariant::Solid => {
+            let bg = button_bg_color(color, theme.clone(), is_hovering);
+            let border = if color == ButtonColor::White || color == ButtonColor::Gray {
+                Some(theme.colors.gray[if dark { 7 } else { 3 }])
+            } else {
+                None
+            };
+
+            ButtonColors {
+                bg,
+                text: button_color(color, theme.clone(), bg),
+                border,
+            }
+        }
+        // ring-1 ring-current
+        ButtonVariant::Outline => {
+            let text = ink(on(tint));
+            ButtonColors {
+                bg: tint,
+                text,
+                border: Some(text),
+            }
+        }
+        // bg-{color}-50 hover:bg-{color}-100 dark:bg-{color}-950 dark:hover:bg-{color}-900
+        ButtonVariant::Soft => {
+            let bg = match (neutral, is_hovering) {
+                (Some(neutral), false) => neutral.soft,
+                (Some(neutral), true) => neutral.soft_hover,
+                (None, false) => scale[if dark { 10 } else { 0 }],
+                (None, true) => scale[if dark { 9 } else { 1 }],
+            };
+            ButtonColors {
+                bg,
+                text: ink(bg),
+                border: None,
+            }
+        }
+        ButtonVariant::Ghost => ButtonColors {
+            bg: tint,
+            text: ink(on(tint)),
+            border: None,
+        },
+        // hover:text-{color}-900 dark:hover:text-{color}-300
+        ButtonVariant::Link => ButtonColors {
+            bg: Srgba::NONE,
+            text: match (is_hovering, neutral) {
+                (false, _) => ink(page),
+                (true, Some(neutral)) => neutral.hover_ink,
+                (true, None) => readable_shade(&scale, if dark { 3 } else { 9 }, page),
+            },
+            border: None,
+        },
+    }
+}
+
+// White, Black and Gray don't have a scale of their own, so each gets fixed
+// tokens off the gray scale that keep the three apart in every variant.
+#[derive(Clone, Copy)]
+struct NeutralTokens {
+    ink: Srgba,
+    hover_ink: Srgba,
+    tint: Srgba,
+    soft: Srgba,
+    soft_hover: Srgba,
+}
+
+fn neutral_tokens(color: ButtonColor, theme: &QuillUiTheme) -> Option<NeutralTokens> {
+    let gray = theme.colors.gray;
+    let (white, black) = (theme.colors.white, theme.colors.black);
+    let dark = theme.mode == ThemeMode::DARK;
+
+    let tokens = match (color, dark) {
+        // text-gray-900 dark:text-white, on white dark:bg-gray-800 fills
+        (ButtonColor::White, false) => NeutralTokens {
+            ink: gray[9],
+            hover_ink: gray[7],
+            tint: white,
+            soft: white,
+            soft_hover: gray[1],
+        },
+        (ButtonColor::White, true) => NeutralTokens {
+            ink: white,
+            hover_ink: gray[3],
+            tint: gray[8],
+            soft: gray[8],
+            soft_hover: gray[7],
+        },
+        // text-black dark:text-gray-50, on gray-300 dark:bg-black fills
+        (ButtonColor::Black, false) => NeutralTokens {
+            ink: black,
+            hover_ink: gray[8],
+            tint: gray[3],
+            soft: gray[3],
+            soft_hover: gray[4],
+        },
+        (ButtonColor::Black, true) => NeutralTokens {
+            ink: gray[0],
+            hover_ink: gray[2],
+            tint: black,
+            soft: black,
+            soft_hover: gray[10],
+        },
+        // text-gray-600 dark:text-gray-300, on the usual gray tints
+        (ButtonColor::Gray, false) => NeutralTokens {
+            ink: gray[6],
+            hover_ink: gray[9],
+            tint: gray[0],
+            soft: gray[0],
+            soft_hover: gray[1],
+        },
+        (ButtonColor::Gray, true) => NeutralTokens {
+            ink: gray[3],
+            hover_ink: white,
+            tint: gray[10],
+            soft: gray[10],
+            soft_hover: gray[9],
+        },
+        _ => return None,
+    };
+
+    Some(tokens)
+}
+
+fn button_bg_color(color: ButtonColor, theme: QuillUiTheme, is_hovering: bool) -> Srgba {
+    let mode = theme.mode;
+    // bg-{color}-600 hover:bg-{color}-700 dark:bg-{color}-400 dark:hover:bg-{color}-300
+    let index = match (mode, is_hovering) {
+        (ThemeMode::DARK, false) => 4,
+        (ThemeMode::DARK, true) => 3,
+        (ThemeMode::LIGHT, false) => 6,
+        (ThemeMode::LIGHT, true) => 7,
     };
 
     if color == ButtonColor::Primary {
@@ -266,7 +847,7 @@ fn button_bg_color(color: ButtonColor, theme: QuillUiTheme, is_hovering: bool) -
 
     return theme.colors.indigo[0];
 }
-fn button_color(color: ButtonColor, theme: QuillUiTheme, is_hovering: bool) -> Srgba {
+fn button_color(color: ButtonColor, theme: QuillUiTheme, bg: Srgba) -> Srgba {
     let mode = theme.mode;
 
     if color == ButtonColor::White {
@@ -287,9 +868,254 @@ fn button_color(color: ButtonColor, theme: QuillUiTheme, is_hovering: bool) -> S
         }
     }
 
-    if mode == ThemeMode::LIGHT {
-        return theme.colors.white;
+    if color == ButtonColor::Black {
+        if mode == ThemeMode::LIGHT {
+            return theme.colors.white;
+        }
+
+        return theme.colors.gray[9];
+    }
+
+    // filled colors: pick whichever text color has the better contrast
+    theme.foreground_for(bg)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        a11y::Focus,
+        input::{keyboard::Key, ButtonState},
+        prelude::{App, Entity, ResMut, Resource},
+        ui::{FlexDirection, Node},
+    };
+    use bevy_quill::Cx;
+    use bevy_quill_obsidian::focus::TabGroup;
+
+    use super::*;
+    use crate::ui::testing::{headless_app, named, send_key, spawn_view, tap_key};
+
+    use ButtonColor as C;
+    use ButtonVariant as V;
+
+    const COLORS: [ButtonColor; 7] = [
+        C::Primary,
+        C::Indigo,
+        C::Blue,
+        C::Green,
+        C::White,
+        C::Black,
+        C::Gray,
+    ];
+    const VARIANTS: [ButtonVariant; 5] = [V::Solid, V::Outline, V::Soft, V::Ghost, V::Link];
+
+    fn theme(dark: bool) -> QuillUiTheme {
+        QuillUiTheme {
+            mode: if dark {
+                ThemeMode::DARK
+            } else {
+                ThemeMode::LIGHT
+            },
+            ..Default::default()
+        }
     }
 
-    return theme.colors.gray[9];
+    #[test]
+    fn resolved_colors_snapshot() {
+        // (dark, color, variant, hovering, bg, text, border)
+        #[rustfmt::skip]
+        let cases = [
+            (true, C::Primary, V::Solid, false, Some("#4ade80"), "#171717", None),
+            (true, C::Primary, V::Outline, false, None, "#4ade80", Some("#4ade80")),
+            (true, C::Primary, V::Soft, false, Some("#052e16"), "#4ade80", None),
+            (true, C::Primary, V::Ghost, true, Some("#052e16"), "#4ade80", None),
+            (true, C::Primary, V::Link, true, None, "#86efac", None),
+            (true, C::White, V::Solid, false, Some("#171717"), "#ffffff", Some("#404040")),
+            (true, C::White, V::Outline, false, None, "#ffffff", Some("#ffffff")),
+            (true, C::White, V::Soft, false, Some("#262626"), "#ffffff", None),
+            (true, C::Black, V::Solid, false, Some("#ffffff"), "#171717", None),
+            (true, C::Black, V::Outline, false, None, "#fafafa", Some("#fafafa")),
+            (true, C::Black, V::Soft, false, Some("#000000"), "#fafafa", None),
+            (true, C::Gray, V::Solid, false, Some("#262626"), "#ffffff", Some("#404040")),
+            (true, C::Gray, V::Outline, false, None, "#d4d4d4", Some("#d4d4d4")),
+            (true, C::Gray, V::Soft, false, Some("#0a0a0a"), "#d4d4d4", None),
+            (false, C::Primary, V::Solid, false, Some("#16a34a"), "#171717", None),
+            (false, C::Primary, V::Outline, false, None, "#166534", Some("#166534")),
+            (false, C::Primary, V::Soft, false, Some("#f0fdf4"), "#15803d", None),
+            (false, C::Primary, V::Link, true, None, "#14532d", None),
+            (false, C::White, V::Solid, false, Some("#ffffff"), "#171717", Some("#d4d4d4")),
+            (false, C::White, V::Outline, false, None, "#171717", Some("#171717")),
+            (false, C::Black, V::Solid, false, Some("#171717"), "#ffffff", None),
+            (false, C::Black, V::Soft, false, Some("#d4d4d4"), "#000000", None),
+            (false, C::Gray, V::Solid, false, Some("#fafafa"), "#404040", Some("#d4d4d4")),
+            (false, C::Gray, V::Ghost, true, Some("#fafafa"), "#525252", None),
+        ];
+
+        let hex = |hex: &str| Srgba::hex(hex).unwrap();
+        for (dark, color, variant, hovering, bg, text, border) in cases {
+            assert_eq!(
+                button_colors(color, variant, theme(dark), hovering),
+                ButtonColors {
+                    bg: bg.map_or(Srgba::NONE, hex),
+                    text: hex(text),
+                    border: border.map(hex),
+                },
+                "{:?} {:?} dark={} hovering={}",
+                color,
+                variant,
+                dark,
+                hovering
+            );
+        }
+    }
+
+    #[test]
+    fn every_color_and_variant_is_distinct() {
+        for dark in [true, false] {
+            let mut theme = theme(dark);
+            // primary defaults to the green scale; give it one of its own
+            theme.colors.primary = theme.colors.rose;
+
+            let mut seen: Vec<(ButtonColor, ButtonVariant, [ButtonColors; 2])> = Vec::new();
+            for color in COLORS {
+                for variant in VARIANTS {
+                    let resolved = [false, true]
+                        .map(|hovering| button_colors(color, variant, theme.clone(), hovering));
+                    for (other_color, other_variant, other) in &seen {
+                        assert_ne!(
+                            &resolved, other,
+                            "{:?} {:?} looks like {:?} {:?} (dark={})",
+                            color, variant, other_color, other_variant, dark
+                        );
+                    }
+                    seen.push((color, variant, resolved));
+                }
+            }
+
+            // at rest, too, no two colors share a look within a variant
+            for variant in VARIANTS {
+                let idle: Vec<_> = COLORS
+                    .iter()
+                    .map(|color| button_colors(*color, variant, theme.clone(), false))
+                    .collect();
+                for (i, colors) in idle.iter().enumerate() {
+                    assert!(
+                        !idle[i + 1..].contains(colors),
+                        "{:?} {:?} (dark={})",
+                        COLORS[i],
+                        variant,
+                        dark
+                    );
+                }
+            }
+        }
+    }
+
+    fn laid_out_width(button: Button) -> f32 {
+        let mut app = headless_app();
+        spawn_view(
+            &mut app,
+            Element::<NodeBundle>::new()
+                .style(|ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(FlexDirection::Row)
+                        .width(400);
+                })
+                .children(button.children("Save")),
+        );
+
+        let button = named(&mut app, "Button")[0];
+        app.world().get::<Node>(button).unwrap().size().x
+    }
+
+    #[test]
+    fn block_button_fills_parent_width() {
+        assert_eq!(laid_out_width(Button::new().block(true)), 400.);
+    }
+
+    #[test]
+    fn inline_button_fits_its_content() {
+        let width = laid_out_width(Button::new());
+        assert!(width > 0. && width < 400., "inline width {}", width);
+    }
+
+    #[derive(Resource, Default)]
+    struct Clicks(usize);
+
+    // Two buttons in a tab group, both counting clicks.
+    #[derive(Clone, PartialEq)]
+    struct TwoButtons;
+
+    impl ViewTemplate for TwoButtons {
+        type View = impl View;
+
+        fn create(&self, cx: &mut Cx) -> Self::View {
+            let on_click = cx.create_callback(|mut clicks: ResMut<Clicks>| clicks.0 += 1);
+
+            Element::<NodeBundle>::new()
+                .insert_dyn(|_| TabGroup::default(), ())
+                .children((
+                    Button::new().on_click(on_click).children("One"),
+                    Button::new().on_click(on_click).children("Two"),
+                ))
+        }
+    }
+
+    fn two_buttons() -> (App, Vec<Entity>) {
+        let mut app = headless_app();
+        app.init_resource::<Clicks>();
+        spawn_view(&mut app, TwoButtons);
+        let buttons = named(&mut app, "Button");
+        assert_eq!(buttons.len(), 2);
+        (app, buttons)
+    }
+
+    fn focused(app: &App) -> Option<Entity> {
+        app.world().resource::<Focus>().0
+    }
+
+    #[test]
+    fn enter_and_space_activate_focused_button() {
+        let (mut app, buttons) = two_buttons();
+        app.world_mut().resource_mut::<Focus>().0 = Some(buttons[0]);
+
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert_eq!(app.world().resource::<Clicks>().0, 1);
+
+        tap_key(&mut app, KeyCode::Space, Key::Space);
+        assert_eq!(app.world().resource::<Clicks>().0, 2);
+
+        // other keys don't click
+        tap_key(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+        assert_eq!(app.world().resource::<Clicks>().0, 2);
+    }
+
+    #[test]
+    fn tab_moves_focus_in_tree_order() {
+        let (mut app, buttons) = two_buttons();
+
+        tap_key(&mut app, KeyCode::Tab, Key::Tab);
+        assert_eq!(focused(&app), Some(buttons[0]));
+
+        tap_key(&mut app, KeyCode::Tab, Key::Tab);
+        assert_eq!(focused(&app), Some(buttons[1]));
+
+        send_key(
+            &mut app,
+            KeyCode::ShiftLeft,
+            Key::Shift,
+            ButtonState::Pressed,
+        );
+        tap_key(&mut app, KeyCode::Tab, Key::Tab);
+        send_key(
+            &mut app,
+            KeyCode::ShiftLeft,
+            Key::Shift,
+            ButtonState::Released,
+        );
+        assert_eq!(focused(&app), Some(buttons[0]));
+
+        // pressing Enter after tabbing activates the newly focused button
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert_eq!(app.world().resource::<Clicks>().0, 1);
+    }
 }
@@ -0,0 +1,176 @@
+use bevy::{
+    prelude::{Component, NodeBundle},
+    ui::{BorderRadius, FlexDirection, Val},
+};
+use bevy_mod_stylebuilder::{StyleBuilder, StyleBuilderLayout, StyleHandle, StyleTuple};
+use bevy_quill::{Cx, Element, For, View, ViewTemplate};
+
+use super::{Button, ButtonColor, ButtonSize};
+
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum ButtonGroupOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+// Size and color inherited by buttons inside a `ButtonGroup`.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct ButtonGroupContext {
+    pub size: Option<ButtonSize>,
+    pub color: Option<ButtonColor>,
+}
+
+// Where a button sits inside a `ButtonGroup`. Only the outer corners are
+// rounded, and bordered segments after the first overlap the previous border.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ButtonSegment {
+    pub orientation: ButtonGroupOrientation,
+    pub first: bool,
+    pub last: bool,
+}
+
+impl ButtonSegment {
+    pub fn border_radius(&self, radius: f32) -> BorderRadius {
+        let round = |outer: bool| if outer { Val::Px(radius) } else { Val::ZERO };
+        let (first, last) = (round(self.first), round(self.last));
+
+        match self.orientation {
+            ButtonGroupOrientation::Horizontal => BorderRadius {
+                top_left: first,
+                bottom_left: first,
+                top_right: last,
+                bottom_right: last,
+            },
+            ButtonGroupOrientation::Vertical => BorderRadius {
+                top_left: first,
+                top_right: first,
+                bottom_left: last,
+                bottom_right: last,
+            },
+        }
+    }
+
+    // Left and top margins for a segment drawn with `border_width`. Without a
+    // border there is nothing to share, and overlapping would clip the
+    // neighbour.
+    pub fn margin(&self, border_width: f32) -> (Val, Val) {
+        let overlap = if self.first || border_width <= 0. {
+            Val::ZERO
+        } else {
+            Val::Px(-border_width)
+        };
+
+        match self.orientation {
+            ButtonGroupOrientation::Horizontal => (overlap, Val::ZERO),
+            ButtonGroupOrientation::Vertical => (Val::ZERO, overlap),
+        }
+    }
+}
+
+#[derive(Default, Clone, PartialEq)]
+pub struct ButtonGroup {
+    pub buttons: Vec<Button>,
+    pub orientation: ButtonGroupOrientation,
+    pub size: Option<ButtonSize>,
+    pub color: Option<ButtonColor>,
+    pub style: StyleHandle,
+}
+
+impl ButtonGroup {
+    pub fn new() -> ButtonGroup {
+        Self::default()
+    }
+
+    pub fn buttons(mut self, buttons: impl IntoIterator<Item = Button>) -> Self {
+        self.buttons = buttons.into_iter().collect();
+        self
+    }
+
+    pub fn orientation(mut self, orientation: ButtonGroupOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for ButtonGroup {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        cx.insert(ButtonGroupContext {
+            size: self.size,
+            color: self.color,
+        });
+
+        let orientation = self.orientation;
+        let last = self.buttons.len().saturating_sub(1);
+
+        Element::<NodeBundle>::new()
+            .named("ButtonGroup")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex);
+                },
+                self.style.clone(),
+            ))
+            .style_dyn(
+                |orientation, ss| {
+                    ss.flex_direction(match orientation {
+                        ButtonGroupOrientation::Horizontal => FlexDirection::Row,
+                        ButtonGroupOrientation::Vertical => FlexDirection::Column,
+                    });
+                },
+                self.orientation,
+            )
+            .children(For::index(&self.buttons, move |button, index| {
+                button.clone().segment(ButtonSegment {
+                    orientation,
+                    first: index == 0,
+                    last: index == last,
+                })
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_bordered_segments_overlap() {
+        let segment = ButtonSegment {
+            orientation: ButtonGroupOrientation::Horizontal,
+            first: false,
+            last: false,
+        };
+        assert_eq!(segment.margin(1.), (Val::Px(-1.), Val::ZERO));
+        assert_eq!(segment.margin(0.), (Val::ZERO, Val::ZERO));
+
+        let first = ButtonSegment {
+            first: true,
+            ..segment
+        };
+        assert_eq!(first.margin(1.), (Val::ZERO, Val::ZERO));
+
+        let vertical = ButtonSegment {
+            orientation: ButtonGroupOrientation::Vertical,
+            ..segment
+        };
+        assert_eq!(vertical.margin(1.), (Val::ZERO, Val::Px(-1.)));
+    }
+}
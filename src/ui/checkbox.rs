@@ -0,0 +1,417 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role, Toggled},
+        AccessibilityNode,
+    },
+    color::{Alpha, Color, Srgba},
+    prelude::{KeyCode, NodeBundle, Quat, Transform, World},
+    ui::UiRect,
+    window::CursorIcon,
+};
+use bevy_mod_picking::prelude::{ListenerInput, On};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderFont, StyleBuilderLayout, StyleBuilderOutline, StyleHandle, StyleTuple,
+};
+use bevy_quill::{
+    Callback, Cond, Element, IntoViewChild, RunCallback, View, ViewChild, ViewTemplate,
+};
+use bevy_quill_obsidian::{
+    controls::{Disabled, IsDisabled},
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+};
+
+use super::{
+    button_ring_color, click_to_focus, ButtonColor, ButtonSize, QuillUiTheme, ThemeMode, UseTheme,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ControlColors {
+    pub fill: Srgba,
+    pub border: Srgba,
+    pub mark: Srgba,
+}
+
+// The "on" fill and border of checkboxes, radios and switches:
+// bg-{color}-500 dark:bg-{color}-400. White and black follow their solid
+// buttons, and gray takes the same shade from the gray scale.
+pub(crate) fn control_fill(color: ButtonColor, theme: &QuillUiTheme) -> (Srgba, Srgba) {
+    let dark = theme.mode == ThemeMode::DARK;
+    let gray = &theme.colors.gray;
+    match color {
+        // bg-white dark:bg-gray-900 ring-gray-300 dark:ring-gray-700
+        ButtonColor::White => {
+            let fill = if dark { gray[9] } else { theme.colors.white };
+            (fill, gray[if dark { 7 } else { 3 }])
+        }
+        // bg-gray-900 dark:bg-white
+        ButtonColor::Black => {
+            let fill = if dark { theme.colors.white } else { gray[9] };
+            (fill, fill)
+        }
+        ButtonColor::Gray => {
+            let fill = gray[if dark { 4 } else { 5 }];
+            (fill, fill)
+        }
+        _ => {
+            let fill = button_ring_color(color, theme.clone());
+            (fill, fill)
+        }
+    }
+}
+
+// Checked: `control_fill` with a contrasting mark.
+// Unchecked: bg-white dark:bg-gray-900 ring-gray-300 dark:ring-gray-700.
+// Disabled: everything faded to the theme's disabled opacity.
+pub(crate) fn control_colors(
+    color: ButtonColor,
+    theme: QuillUiTheme,
+    checked: bool,
+    is_hovering: bool,
+    disabled: bool,
+) -> ControlColors {
+    let dark = theme.mode == ThemeMode::DARK;
+    let colors = if checked {
+        let (fill, border) = control_fill(color, &theme);
+        ControlColors {
+            fill,
+            border,
+            mark: theme.foreground_for(fill),
+        }
+    } else {
+        ControlColors {
+            fill: if dark {
+                theme.colors.gray[9]
+            } else {
+                theme.colors.white
+            },
+            border: match (dark, is_hovering) {
+                (true, false) => theme.colors.gray[7],
+                (true, true) => theme.colors.gray[5],
+                (false, false) => theme.colors.gray[3],
+                (false, true) => theme.colors.gray[4],
+            },
+            mark: Srgba::NONE,
+        }
+    };
+
+    if disabled {
+        let opacity = theme.disabled_opacity();
+        let dim = |c: Srgba| c.with_alpha(c.alpha * opacity);
+        ControlColors {
+            fill: dim(colors.fill),
+            border: dim(colors.border),
+            mark: dim(colors.mark),
+        }
+    } else {
+        colors
+    }
+}
+
+#[derive(Default, Clone, PartialEq)]
+pub struct Checkbox {
+    pub label: ViewChild,
+    pub checked: bool,
+    // Shown as a dash; takes precedence over `checked`.
+    pub indeterminate: bool,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+    pub disabled: bool,
+    pub tab_index: i32,
+
+    pub on_change: Option<Callback<bool>>,
+}
+
+impl Checkbox {
+    pub fn new() -> Checkbox {
+        Self::default()
+    }
+
+    pub fn label(mut self, label: impl IntoViewChild) -> Self {
+        self.label = label.into_view_child();
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<bool>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Checkbox {
+    type View = impl View;
+
+    fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
+        let id = cx.create_entity();
+        let disabled = self.disabled;
+        let hovering = cx.is_hovered(id) && !disabled;
+        let focused = cx.is_focus_visible(id);
+        let theme = cx.use_theme();
+        let tokens = theme.size(self.size);
+        let filled = self.checked || self.indeterminate;
+        let colors = control_colors(self.color, theme.clone(), filled, hovering, disabled);
+        let box_size = tokens.font_size;
+        let ring = button_ring_color(self.color, theme.clone());
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Checkbox")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Row)
+                        .align_items(bevy::ui::AlignItems::Center);
+                },
+                self.style.clone(),
+            ))
+            .style_dyn(
+                |(tokens, text, disabled), ss| {
+                    ss.column_gap(tokens.gap)
+                        .font_size(tokens.font_size)
+                        .color(text)
+                        .cursor(if disabled {
+                            CursorIcon::NotAllowed
+                        } else {
+                            CursorIcon::Pointer
+                        });
+                },
+                (tokens, theme.text(), disabled),
+            )
+            .insert_if(disabled, || Disabled)
+            .insert_dyn(
+                move |(checked, indeterminate, disabled, tab_index, on_change)| {
+                    let mut node = NodeBuilder::new(Role::CheckBox);
+                    node.set_toggled(if indeterminate {
+                        Toggled::Mixed
+                    } else if checked {
+                        Toggled::True
+                    } else {
+                        Toggled::False
+                    });
+                    if disabled {
+                        node.set_disabled();
+                    }
+
+                    // An indeterminate checkbox becomes checked when toggled.
+                    let toggle = move |world: &mut World| {
+                        if let Some(on_change) = on_change {
+                            world.run_callback(on_change, indeterminate || !checked);
+                        }
+                    };
+
+                    (
+                        AccessibilityNode::from(node),
+                        TabIndex(if disabled { -1 } else { tab_index }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .unwrap();
+                                if !event.repeat && event.key_code == KeyCode::Space {
+                                    event.stop_propagation();
+                                    toggle(world);
+                                }
+                            }
+                        }),
+                        click_to_focus(id, toggle),
+                    )
+                },
+                (
+                    self.checked,
+                    self.indeterminate,
+                    disabled,
+                    self.tab_index,
+                    self.on_change,
+                ),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("Checkbox::Box")
+                    .style_dyn(
+                        |(size, radius, colors, focused, ring), ss: &mut StyleBuilder| {
+                            ss.display(bevy::ui::Display::Flex)
+                                .justify_content(bevy::ui::JustifyContent::Center)
+                                .align_items(bevy::ui::AlignItems::Center)
+                                .flex_shrink(0.)
+                                .width(size)
+                                .height(size)
+                                .border(1)
+                                .border_radius(radius)
+                                .border_color(colors.border)
+                                .background_color(colors.fill);
+                            if focused {
+                                ss.outline_color(ring).outline_width(2).outline_offset(2);
+                            } else {
+                                ss.outline_color(Color::NONE);
+                            }
+                        },
+                        (box_size, tokens.radius.min(4.), colors, focused, ring),
+                    )
+                    .children(Cond::new(
+                        self.indeterminate,
+                        // dash
+                        Element::<NodeBundle>::new().style_dyn(
+                            |(size, color), ss: &mut StyleBuilder| {
+                                ss.width(size * 0.5).height(2).background_color(color);
+                            },
+                            (box_size, colors.mark),
+                        ),
+                        Cond::new(
+                            self.checked,
+                            // an L shape rotated into a check mark
+                            Element::<NodeBundle>::new()
+                                .style_dyn(
+                                    |(size, color), ss: &mut StyleBuilder| {
+                                        ss.width(size * 0.3)
+                                            .height(size * 0.55)
+                                            .margin_bottom(size * 0.1)
+                                            .border(UiRect {
+                                                right: bevy::ui::Val::Px(2.),
+                                                bottom: bevy::ui::Val::Px(2.),
+                                                ..Default::default()
+                                            })
+                                            .border_color(color);
+                                    },
+                                    (box_size, colors.mark),
+                                )
+                                .insert_dyn(
+                                    |_| Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_4)),
+                                    (),
+                                ),
+                            (),
+                        ),
+                    )),
+                self.label.clone(),
+            ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        a11y::Focus,
+        input::keyboard::Key,
+        prelude::{App, In, ResMut, Resource},
+    };
+    use bevy_quill::Cx;
+
+    use super::*;
+    use crate::ui::testing::{headless_app, named, spawn_view, tap_key};
+
+    #[test]
+    fn neutral_colors_have_their_own_fill() {
+        for mode in [ThemeMode::DARK, ThemeMode::LIGHT] {
+            let theme = QuillUiTheme {
+                mode,
+                ..Default::default()
+            };
+            let fill = |color| control_colors(color, theme.clone(), true, false, false).fill;
+            let fills = [
+                fill(ButtonColor::Primary),
+                fill(ButtonColor::White),
+                fill(ButtonColor::Black),
+                fill(ButtonColor::Gray),
+            ];
+            for (i, a) in fills.iter().enumerate() {
+                for b in &fills[i + 1..] {
+                    assert_ne!(a, b, "{:?}", mode);
+                }
+            }
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Checked(bool);
+
+    #[derive(Clone, PartialEq)]
+    struct CheckboxView {
+        disabled: bool,
+    }
+
+    impl ViewTemplate for CheckboxView {
+        type View = impl View;
+
+        fn create(&self, cx: &mut Cx) -> Self::View {
+            let checked = cx.use_resource::<Checked>().0;
+            let on_change = cx.create_callback(|value: In<bool>, mut checked: ResMut<Checked>| {
+                checked.0 = *value;
+            });
+
+            Checkbox::new()
+                .checked(checked)
+                .disabled(self.disabled)
+                .on_change(on_change)
+                .label("Notify me")
+        }
+    }
+
+    fn focused_checkbox(disabled: bool) -> App {
+        let mut app = headless_app();
+        app.init_resource::<Checked>();
+        spawn_view(&mut app, CheckboxView { disabled });
+        let checkbox = named(&mut app, "Checkbox")[0];
+        app.world_mut().resource_mut::<Focus>().0 = Some(checkbox);
+        app
+    }
+
+    #[test]
+    fn space_toggles_the_focused_checkbox() {
+        let mut app = focused_checkbox(false);
+
+        tap_key(&mut app, KeyCode::Space, Key::Space);
+        assert!(app.world().resource::<Checked>().0);
+        tap_key(&mut app, KeyCode::Space, Key::Space);
+        assert!(!app.world().resource::<Checked>().0);
+
+        // Enter submits forms; it doesn't toggle.
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert!(!app.world().resource::<Checked>().0);
+    }
+
+    #[test]
+    fn disabled_checkbox_ignores_space() {
+        let mut app = focused_checkbox(true);
+
+        tap_key(&mut app, KeyCode::Space, Key::Space);
+        assert!(!app.world().resource::<Checked>().0);
+    }
+}
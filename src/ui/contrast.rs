@@ -0,0 +1,245 @@
+use bevy::color::{LinearRgba, Srgba};
+
+use super::{button_colors, ButtonColor, ButtonVariant, QuillUiTheme};
+
+// WCAG 2.x AA threshold for normal-sized text.
+pub const WCAG_AA: f32 = 4.5;
+
+// WCAG 2.x AAA threshold for normal-sized text.
+pub const WCAG_AAA: f32 = 7.0;
+
+pub trait Contrast {
+    fn relative_luminance(&self) -> f32;
+    fn contrast_ratio(&self, other: Srgba) -> f32;
+    fn apca_contrast(&self, background: Srgba) -> f32;
+}
+
+impl Contrast for Srgba {
+    fn relative_luminance(&self) -> f32 {
+        let linear = LinearRgba::from(*self);
+        0.2126 * linear.red + 0.7152 * linear.green + 0.0722 * linear.blue
+    }
+
+    fn contrast_ratio(&self, other: Srgba) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    // APCA 0.0.98G lightness contrast (Lc) of `self` as text on `background`.
+    // Positive for dark text on light backgrounds, negative for the reverse.
+    fn apca_contrast(&self, background: Srgba) -> f32 {
+        fn screen_luminance(color: Srgba) -> f32 {
+            let y = 0.2126729 * color.red.powf(2.4)
+                + 0.7151522 * color.green.powf(2.4)
+                + 0.0721750 * color.blue.powf(2.4);
+            if y > 0.022 {
+                y
+            } else {
+                y + (0.022 - y).powf(1.414)
+            }
+        }
+
+        let text = screen_luminance(*self);
+        let bg = screen_luminance(background);
+
+        if (bg - text).abs() < 0.0005 {
+            return 0.0;
+        }
+
+        let lc = if bg > text {
+            let sapc = (bg.powf(0.56) - text.powf(0.57)) * 1.14;
+            if sapc < 0.1 {
+                0.0
+            } else {
+                sapc - 0.027
+            }
+        } else {
+            let sapc = (bg.powf(0.65) - text.powf(0.62)) * 1.14;
+            if sapc > -0.1 {
+                0.0
+            } else {
+                sapc + 0.027
+            }
+        };
+
+        lc * 100.0
+    }
+}
+
+pub fn best_contrast(background: Srgba, candidates: &[Srgba]) -> Srgba {
+    candidates
+        .iter()
+        .copied()
+        .max_by(|a, b| {
+            a.contrast_ratio(background)
+                .total_cmp(&b.contrast_ratio(background))
+        })
+        .unwrap_or(Srgba::WHITE)
+}
+
+// The first shade of `scale` from `from` onwards that reaches WCAG AA on
+// `background`, stepping darker on light backgrounds and lighter on dark ones.
+pub fn readable_shade(scale: &[Srgba; 11], from: usize, background: Srgba) -> Srgba {
+    let light = background.relative_luminance() > 0.179;
+    let mut shade = from.min(10);
+
+    loop {
+        let color = scale[shade];
+        let next = if light {
+            shade + 1
+        } else {
+            shade.wrapping_sub(1)
+        };
+        if color.contrast_ratio(background) >= WCAG_AA || next > 10 {
+            return color;
+        }
+        shade = next;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContrastFailure {
+    pub foreground: String,
+    pub background: String,
+    pub ratio: f32,
+    pub apca: f32,
+}
+
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct ContrastReport {
+    pub failures: Vec<ContrastFailure>,
+}
+
+impl ContrastReport {
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    fn check(&mut self, foreground: (String, Srgba), background: (String, Srgba)) {
+        let ratio = foreground.1.contrast_ratio(background.1);
+        if ratio < WCAG_AA {
+            self.failures.push(ContrastFailure {
+                foreground: foreground.0,
+                background: background.0,
+                ratio,
+                apca: foreground.1.apca_contrast(background.1),
+            });
+        }
+    }
+}
+
+impl QuillUiTheme {
+    // Picks white or near-black text, whichever reads better on `background`.
+    pub fn foreground_for(&self, background: Srgba) -> Srgba {
+        best_contrast(background, &[self.colors.white, self.colors.gray[9]])
+    }
+
+    pub fn validate(&self) -> ContrastReport {
+        let mut report = ContrastReport::default();
+
+        let text = ("text".to_string(), self.text());
+        report.check(text.clone(), ("background".to_string(), self.background()));
+        report.check(text, ("surface".to_string(), self.surface()));
+
+        for color in [
+            ButtonColor::Primary,
+            ButtonColor::Indigo,
+            ButtonColor::Blue,
+            ButtonColor::Green,
+            ButtonColor::White,
+            ButtonColor::Black,
+            ButtonColor::Gray,
+        ] {
+            for variant in [
+                ButtonVariant::Solid,
+                ButtonVariant::Outline,
+                ButtonVariant::Soft,
+                ButtonVariant::Ghost,
+                ButtonVariant::Link,
+            ] {
+                for hovering in [false, true] {
+                    let name = format!(
+                        "button.{:?}.{:?}.{}",
+                        variant,
+                        color,
+                        if hovering { "hover" } else { "idle" }
+                    );
+                    let colors = button_colors(color, variant, self.clone(), hovering);
+                    // transparent variants sit on the page background
+                    let bg = if colors.bg.alpha == 0.0 {
+                        self.background()
+                    } else {
+                        colors.bg
+                    };
+                    report.check(
+                        (format!("{}.text", name), colors.text),
+                        (format!("{}.bg", name), bg),
+                    );
+                }
+            }
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(hex: &str) -> Srgba {
+        Srgba::hex(hex).unwrap()
+    }
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 0.05,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn contrast_ratio_spans_one_to_twenty_one() {
+        assert_near(Srgba::WHITE.contrast_ratio(Srgba::BLACK), 21.0);
+        assert_near(Srgba::BLACK.contrast_ratio(Srgba::WHITE), 21.0);
+        assert_near(hex("#16a34a").contrast_ratio(hex("#16a34a")), 1.0);
+        // Tailwind green-700 on white, as published by WebAIM.
+        assert_near(hex("#15803d").contrast_ratio(Srgba::WHITE), 5.02);
+    }
+
+    // Reference values from the APCA 0.0.98G test suite.
+    #[test]
+    fn apca_matches_reference_pairs() {
+        assert_near(hex("#888888").apca_contrast(hex("#ffffff")), 63.06);
+        assert_near(hex("#ffffff").apca_contrast(hex("#888888")), -68.54);
+        assert_near(hex("#000000").apca_contrast(hex("#aaaaaa")), 58.15);
+        assert_near(hex("#aaaaaa").apca_contrast(hex("#000000")), -56.24);
+        assert_near(hex("#112233").apca_contrast(hex("#ddeeff")), 91.67);
+        assert_near(hex("#ddeeff").apca_contrast(hex("#112233")), -93.07);
+        assert_eq!(hex("#777777").apca_contrast(hex("#777777")), 0.0);
+    }
+
+    #[test]
+    fn best_contrast_picks_the_strongest_candidate() {
+        let dark = hex("#171717");
+        assert_eq!(best_contrast(Srgba::WHITE, &[Srgba::WHITE, dark]), dark);
+        assert_eq!(best_contrast(dark, &[dark, Srgba::WHITE]), Srgba::WHITE);
+        assert_eq!(best_contrast(dark, &[]), Srgba::WHITE);
+    }
+
+    #[test]
+    fn readable_shade_returns_first_shade_meeting_aa() {
+        let green = QuillUiTheme::default().colors.green;
+
+        // Steps darker on light backgrounds: 700 is the first to pass on white.
+        assert_eq!(readable_shade(&green, 4, Srgba::WHITE), green[7]);
+        assert_eq!(readable_shade(&green, 8, Srgba::WHITE), green[8]);
+        // And lighter on dark ones: 600 is the first to pass on black.
+        assert_eq!(readable_shade(&green, 9, Srgba::BLACK), green[6]);
+        // Settles for the last shade when none passes on a mid gray.
+        assert_eq!(readable_shade(&green, 8, hex("#767676")), green[10]);
+    }
+}
@@ -0,0 +1,300 @@
+use bevy::{
+    a11y::Focus,
+    input::ButtonInput,
+    math::{Rect, Vec2},
+    prelude::{
+        Added, Children, Commands, Component, Entity, GlobalTransform, MouseButton, Parent, Query,
+        Res, ResMut, With, World,
+    },
+    ui::{Node, Style, Val},
+    window::{PrimaryWindow, Window},
+};
+use bevy_quill::{Callback, RunCallback};
+use bevy_quill_obsidian::focus::TabIndex;
+
+use super::{focusables, tab_stops};
+
+// Floating layers are drawn above everything else in the window.
+pub const FLOATING_Z: i32 = 1000;
+
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum FloatSide {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl FloatSide {
+    pub fn opposite(self) -> FloatSide {
+        match self {
+            FloatSide::Top => FloatSide::Bottom,
+            FloatSide::Bottom => FloatSide::Top,
+            FloatSide::Left => FloatSide::Right,
+            FloatSide::Right => FloatSide::Left,
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum FloatAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct FloatPlacement {
+    pub side: FloatSide,
+    pub align: FloatAlign,
+}
+
+impl FloatPlacement {
+    pub fn new(side: FloatSide, align: FloatAlign) -> FloatPlacement {
+        FloatPlacement { side, align }
+    }
+}
+
+// Positions an absolutely positioned node next to `anchor`, flipping to the
+// opposite side when it would overflow the window. See `position_floating`.
+#[derive(Component, Clone, Copy, PartialEq)]
+pub struct Floating {
+    pub anchor: Entity,
+    pub placement: FloatPlacement,
+    pub gap: f32,
+    // Makes the floating node as wide as the anchor.
+    pub match_width: bool,
+}
+
+// The side a `Floating` node ended up on after flipping.
+#[derive(Component, Clone, Copy, PartialEq)]
+pub struct FloatingResolved(pub FloatSide);
+
+// Runs `on_dismiss` when the user clicks outside the floating node (and its
+// anchor). Escape is left to each layer's `KeyPressEvent` handler, which stops
+// propagation so only the innermost layer closes.
+#[derive(Component, Clone, Copy, PartialEq)]
+pub struct FloatingDismiss {
+    pub anchor: Option<Entity>,
+    pub on_dismiss: Callback<()>,
+}
+
+// Moves focus to the first tab stop inside a floating node once it appears.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct FloatingAutoFocus;
+
+fn node_rect(node: &Node, transform: &GlobalTransform) -> Rect {
+    Rect::from_center_size(transform.translation().truncate(), node.size())
+}
+
+fn place(placement: FloatPlacement, anchor: Rect, size: Vec2, gap: f32) -> Vec2 {
+    let cross = |start: f32, end: f32, len: f32| match placement.align {
+        FloatAlign::Start => start,
+        FloatAlign::Center => (start + end - len) / 2.,
+        FloatAlign::End => end - len,
+    };
+    match placement.side {
+        FloatSide::Top => Vec2::new(
+            cross(anchor.min.x, anchor.max.x, size.x),
+            anchor.min.y - gap - size.y,
+        ),
+        FloatSide::Bottom => Vec2::new(
+            cross(anchor.min.x, anchor.max.x, size.x),
+            anchor.max.y + gap,
+        ),
+        FloatSide::Left => Vec2::new(
+            anchor.min.x - gap - size.x,
+            cross(anchor.min.y, anchor.max.y, size.y),
+        ),
+        FloatSide::Right => Vec2::new(
+            anchor.max.x + gap,
+            cross(anchor.min.y, anchor.max.y, size.y),
+        ),
+    }
+}
+
+fn overflows(side: FloatSide, position: Vec2, size: Vec2, window: Vec2) -> bool {
+    match side {
+        FloatSide::Top => position.y < 0.,
+        FloatSide::Bottom => position.y + size.y > window.y,
+        FloatSide::Left => position.x < 0.,
+        FloatSide::Right => position.x + size.x > window.x,
+    }
+}
+
+pub(crate) fn position_floating(
+    mut commands: Commands,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    nodes: Query<(&Node, &GlobalTransform)>,
+    mut query: Query<(
+        Entity,
+        &Floating,
+        &Parent,
+        &mut Style,
+        Option<&FloatingResolved>,
+    )>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let window = Vec2::new(window.width(), window.height());
+
+    for (entity, floating, parent, mut style, resolved) in query.iter_mut() {
+        let (Ok(anchor), Ok(own), Ok(parent)) = (
+            nodes.get(floating.anchor),
+            nodes.get(entity),
+            nodes.get(parent.get()),
+        ) else {
+            continue;
+        };
+        let anchor = node_rect(anchor.0, anchor.1);
+        let mut size = own.0.size();
+        if floating.match_width {
+            size.x = anchor.width();
+        }
+
+        let mut placement = floating.placement;
+        let mut position = place(placement, anchor, size, floating.gap);
+        if overflows(placement.side, position, size, window) {
+            let flipped = FloatPlacement::new(placement.side.opposite(), placement.align);
+            let alternate = place(flipped, anchor, size, floating.gap);
+            if !overflows(flipped.side, alternate, size, window) {
+                placement = flipped;
+                position = alternate;
+            }
+        }
+
+        // Keep the cross axis inside the window.
+        match placement.side {
+            FloatSide::Top | FloatSide::Bottom => {
+                position.x = position.x.clamp(0., (window.x - size.x).max(0.));
+            }
+            FloatSide::Left | FloatSide::Right => {
+                position.y = position.y.clamp(0., (window.y - size.y).max(0.));
+            }
+        }
+
+        // Absolute positions are relative to the parent node.
+        let origin = node_rect(parent.0, parent.1).min;
+        let left = Val::Px(position.x - origin.x);
+        let top = Val::Px(position.y - origin.y);
+        if style.left != left || style.top != top {
+            style.left = left;
+            style.top = top;
+        }
+        if floating.match_width && style.width != Val::Px(size.x) {
+            style.width = Val::Px(size.x);
+        }
+        if resolved.map(|r| r.0) != Some(placement.side) {
+            commands
+                .entity(entity)
+                .insert(FloatingResolved(placement.side));
+        }
+    }
+}
+
+// Whether `point` lies inside `entity`, any of its descendants, or a layer
+// anchored to one of them, so that nested floating layers count as part of
+// their parent even when mounted under the overlay root. `anchored` holds
+// (layer, anchor) pairs.
+fn contains(
+    entity: Entity,
+    point: Vec2,
+    nodes: &Query<(&Node, &GlobalTransform)>,
+    children: &Query<&Children>,
+    anchored: &[(Entity, Entity)],
+) -> bool {
+    if let Ok((node, transform)) = nodes.get(entity) {
+        if node_rect(node, transform).contains(point) {
+            return true;
+        }
+    }
+    let in_layer = anchored.iter().any(|(layer, anchor)| {
+        *anchor == entity && contains(*layer, point, nodes, children, anchored)
+    });
+    in_layer
+        || children.get(entity).is_ok_and(|list| {
+            list.iter()
+                .any(|child| contains(*child, point, nodes, children, anchored))
+        })
+}
+
+pub(crate) fn dismiss_floating(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    nodes: Query<(&Node, &GlobalTransform)>,
+    children: Query<&Children>,
+    query: Query<(Entity, &FloatingDismiss)>,
+) {
+    if mouse.get_just_pressed().next().is_none() {
+        return;
+    }
+    let Some(point) = windows
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+    else {
+        return;
+    };
+
+    let anchored: Vec<(Entity, Entity)> = query
+        .iter()
+        .filter_map(|(entity, dismiss)| Some((entity, dismiss.anchor?)))
+        .collect();
+
+    for (entity, dismiss) in query.iter() {
+        let outside = !contains(entity, point, &nodes, &children, &anchored)
+            && !dismiss
+                .anchor
+                .is_some_and(|anchor| contains(anchor, point, &nodes, &children, &anchored));
+        if outside {
+            let on_dismiss = dismiss.on_dismiss;
+            commands.add(move |world: &mut World| {
+                world.run_callback(on_dismiss, ());
+            });
+        }
+    }
+}
+
+pub(crate) fn autofocus_floating(
+    mut focus: ResMut<Focus>,
+    added: Query<Entity, Added<FloatingAutoFocus>>,
+    children: Query<&Children>,
+    tab_indices: Query<&TabIndex>,
+) {
+    for entity in added.iter() {
+        let mut stops = Vec::new();
+        focusables(entity, &children, &tab_indices, &mut stops);
+        if let Some(first) = stops.first() {
+            focus.0 = Some(*first);
+        }
+    }
+}
+
+pub(crate) fn focus_first(world: &mut World, container: Entity) {
+    if let Some(first) = tab_stops(world, container).first() {
+        world.resource_mut::<Focus>().0 = Some(*first);
+    }
+}
+
+// Moves focus `step` tab stops forward or back within `container`, wrapping
+// around. Used for arrow key navigation inside floating content.
+pub(crate) fn focus_step(world: &mut World, container: Entity, step: isize) {
+    let stops = tab_stops(world, container);
+    if stops.is_empty() {
+        return;
+    }
+    let current = world
+        .resource::<Focus>()
+        .0
+        .and_then(|focused| stops.iter().position(|stop| *stop == focused));
+    let next = match current {
+        Some(index) => (index as isize + step).rem_euclid(stops.len() as isize) as usize,
+        None if step < 0 => stops.len() - 1,
+        None => 0,
+    };
+    world.resource_mut::<Focus>().0 = Some(stops[next]);
+}
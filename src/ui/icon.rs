@@ -0,0 +1,88 @@
+use bevy::{
+    color::Srgba,
+    prelude::{Handle, Image, ImageBundle, NodeBundle},
+    text::Font,
+    ui::UiImage,
+};
+use bevy_mod_stylebuilder::{StyleBuilder, StyleBuilderFont, StyleBuilderLayout};
+use bevy_quill::{Cond, Element, View, ViewTemplate};
+
+#[derive(Clone, PartialEq)]
+pub enum Icon {
+    Image(Handle<Image>),
+    Glyph { font: Handle<Font>, glyph: char },
+}
+
+impl Icon {
+    pub fn image(image: Handle<Image>) -> Self {
+        Icon::Image(image)
+    }
+
+    pub fn glyph(font: Handle<Font>, glyph: char) -> Self {
+        Icon::Glyph { font, glyph }
+    }
+}
+
+// Renders nothing when `icon` is `None`, so optional icon slots can use it directly.
+#[derive(Clone, PartialEq)]
+pub struct IconView {
+    pub icon: Option<Icon>,
+    pub size: f32,
+    pub color: Srgba,
+}
+
+impl IconView {
+    pub fn new(icon: Option<Icon>, size: f32, color: Srgba) -> Self {
+        Self { icon, size, color }
+    }
+}
+
+impl ViewTemplate for IconView {
+    type View = impl View;
+
+    fn create(&self, _cx: &mut bevy_quill::Cx) -> Self::View {
+        let size = self.size;
+        let color = self.color;
+        let (image, font, glyph) = match self.icon.clone() {
+            Some(Icon::Image(image)) => (Some(image), Handle::default(), String::new()),
+            Some(Icon::Glyph { font, glyph }) => (None, font, glyph.to_string()),
+            None => (None, Handle::default(), String::new()),
+        };
+
+        Cond::new(
+            self.icon.is_some(),
+            Cond::new(
+                image.is_some(),
+                Element::<ImageBundle>::new()
+                    .named("Icon")
+                    .style_dyn(
+                        |size, ss: &mut StyleBuilder| {
+                            ss.width(size).height(size).flex_shrink(0.);
+                        },
+                        size,
+                    )
+                    .insert_dyn(
+                        |(image, color)| UiImage::new(image).with_color(color.into()),
+                        (image.unwrap_or_default(), color),
+                    ),
+                Element::<NodeBundle>::new()
+                    .named("Icon")
+                    .style_dyn(
+                        |(font, size, color), ss: &mut StyleBuilder| {
+                            ss.width(size)
+                                .height(size)
+                                .flex_shrink(0.)
+                                .justify_content(bevy::ui::JustifyContent::Center)
+                                .align_items(bevy::ui::AlignItems::Center)
+                                .font_handle(font)
+                                .font_size(size)
+                                .color(color);
+                        },
+                        (font, size, color),
+                    )
+                    .children(glyph),
+            ),
+            (),
+        )
+    }
+}
@@ -0,0 +1,586 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    color::{Alpha, Srgba},
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonInput, ButtonState,
+    },
+    prelude::{
+        Commands, Component, EventReader, KeyCode, NodeBundle, Query, Res, ResMut, Without, World,
+    },
+    ui::Val,
+    window::CursorIcon,
+};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderFont, StyleBuilderLayout, StyleHandle, StyleTuple,
+};
+use bevy_quill::{Callback, Cond, Element, RunCallback, View, ViewTemplate};
+use bevy_quill_obsidian::{
+    controls::Disabled, cursor::StyleBuilderCursor, focus::TabIndex, hooks::UseIsFocus,
+};
+
+use super::{
+    button_ring_color, click_to_focus, ButtonColor, ButtonSize, Icon, IconView, InternalClipboard,
+    QuillUiTheme, TextBuffer, ThemeMode, UseTheme,
+};
+
+// Callbacks for the entity holding a `TextBuffer`, see `edit_text_inputs`.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct TextInput {
+    pub on_change: Option<Callback<String>>,
+    pub on_submit: Option<Callback<String>>,
+    // Enter inserts a newline; Ctrl+Enter submits.
+    pub multiline: bool,
+    pub max_length: Option<usize>,
+}
+
+#[derive(Default, Clone, PartialEq)]
+pub struct Input {
+    pub value: String,
+    pub placeholder: String,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+    pub disabled: bool,
+    pub tab_index: i32,
+    // Takes focus when the input is first shown.
+    pub autofocus: bool,
+    pub leading_icon: Option<Icon>,
+    pub trailing_icon: Option<Icon>,
+
+    pub on_change: Option<Callback<String>>,
+    pub on_submit: Option<Callback<String>>,
+}
+
+impl Input {
+    pub fn new() -> Input {
+        Self::default()
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+
+    pub fn leading_icon(mut self, icon: Icon) -> Self {
+        self.leading_icon = Some(icon);
+        self
+    }
+
+    pub fn trailing_icon(mut self, icon: Icon) -> Self {
+        self.trailing_icon = Some(icon);
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<String>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn on_submit(mut self, callback: Callback<String>) -> Self {
+        self.on_submit = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InputColors {
+    pub bg: Srgba,
+    pub text: Srgba,
+    pub placeholder: Srgba,
+    pub border: Srgba,
+}
+
+// bg-white dark:bg-gray-900 text-gray-900 dark:text-white
+// placeholder-gray-400 dark:placeholder-gray-500 ring-gray-300 dark:ring-gray-700
+pub(crate) fn input_colors(theme: &QuillUiTheme) -> InputColors {
+    if theme.mode == ThemeMode::DARK {
+        InputColors {
+            bg: theme.colors.gray[9],
+            text: theme.colors.white,
+            placeholder: theme.colors.gray[5],
+            border: theme.colors.gray[7],
+        }
+    } else {
+        InputColors {
+            bg: theme.colors.white,
+            text: theme.colors.gray[9],
+            placeholder: theme.colors.gray[4],
+            border: theme.colors.gray[3],
+        }
+    }
+}
+
+impl ViewTemplate for Input {
+    type View = impl View;
+
+    fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
+        let id = cx.create_entity();
+        let disabled = self.disabled;
+        let focused = cx.is_focused(id) && !disabled;
+        let theme = cx.use_theme();
+        let tokens = theme.size(self.size);
+        let colors = input_colors(&theme);
+        let ring = button_ring_color(self.color, theme.clone());
+
+        // The buffer owns the caret and selection; `value` only replaces its text
+        // when the owner changes it from outside.
+        cx.create_effect(
+            move |world: &mut World, value: String| match world.get_mut::<TextBuffer>(id) {
+                Some(mut buffer) => {
+                    if buffer.text != value {
+                        buffer.set_text(value);
+                    }
+                }
+                None => {
+                    world.entity_mut(id).insert(TextBuffer::new(value));
+                }
+            },
+            self.value.clone(),
+        );
+        cx.create_effect(
+            move |world: &mut World, autofocus| {
+                if autofocus {
+                    world.resource_mut::<Focus>().0 = Some(id);
+                }
+            },
+            self.autofocus && !disabled,
+        );
+        let buffer = cx
+            .use_component::<TextBuffer>(id)
+            .cloned()
+            .unwrap_or_else(|| TextBuffer::new(self.value.clone()));
+
+        let (before, selected, after) = buffer.segments();
+        let caret_at_start = buffer.caret == buffer.selection().start;
+        let caret = move || {
+            Element::<NodeBundle>::new().named("Caret").style_dyn(
+                |(color, height), ss: &mut StyleBuilder| {
+                    ss.width(1).height(height).background_color(color);
+                },
+                (colors.text, tokens.font_size),
+            )
+        };
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Input")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Row)
+                        .align_items(bevy::ui::AlignItems::Center)
+                        .width(Val::Percent(100.));
+                },
+                self.style.clone(),
+            ))
+            .style_dyn(
+                |(colors, ring, focused, disabled, tokens, opacity), sb| {
+                    let dim = |c: Srgba| {
+                        if disabled {
+                            c.with_alpha(c.alpha * opacity)
+                        } else {
+                            c
+                        }
+                    };
+
+                    // focus:ring-2 focus:ring-{color}-500 dark:focus:ring-{color}-400
+                    sb.background_color(dim(colors.bg))
+                        .color(dim(colors.text))
+                        .border(if focused { 2 } else { 1 })
+                        .border_color(dim(if focused { ring } else { colors.border }))
+                        .border_radius(tokens.radius)
+                        .column_gap(tokens.gap)
+                        .font_size(tokens.font_size)
+                        .padding((tokens.padding_x, tokens.padding_y))
+                        .cursor(if disabled {
+                            CursorIcon::NotAllowed
+                        } else {
+                            CursorIcon::Text
+                        });
+                },
+                (
+                    colors,
+                    ring,
+                    focused,
+                    disabled,
+                    tokens,
+                    theme.disabled_opacity(),
+                ),
+            )
+            .insert_if(disabled, || Disabled)
+            .insert_dyn(
+                move |(disabled, tab_index, value, on_change, on_submit)| {
+                    let mut node = NodeBuilder::new(Role::TextInput);
+                    node.set_value(value);
+                    if disabled {
+                        node.set_disabled();
+                    }
+
+                    (
+                        AccessibilityNode::from(node),
+                        TabIndex(if disabled { -1 } else { tab_index }),
+                        TextInput {
+                            on_change,
+                            on_submit,
+                            ..Default::default()
+                        },
+                        click_to_focus(id, |_: &mut World| {}),
+                    )
+                },
+                (
+                    disabled,
+                    self.tab_index,
+                    buffer.text.clone(),
+                    self.on_change,
+                    self.on_submit,
+                ),
+            )
+            .children((
+                IconView::new(
+                    self.leading_icon.clone(),
+                    tokens.icon_size,
+                    colors.placeholder,
+                ),
+                Element::<NodeBundle>::new()
+                    .named("Input::Text")
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.display(bevy::ui::Display::Flex)
+                            .flex_direction(bevy::ui::FlexDirection::Row)
+                            .align_items(bevy::ui::AlignItems::Center)
+                            .flex_grow(1.)
+                            .min_width(0)
+                            .overflow(bevy::ui::OverflowAxis::Clip);
+                    })
+                    .children((
+                        before,
+                        Cond::new(focused && caret_at_start, caret(), ()),
+                        Element::<NodeBundle>::new()
+                            .named("Input::Selection")
+                            .style_dyn(
+                                |color, ss: &mut StyleBuilder| {
+                                    ss.background_color(color);
+                                },
+                                ring.with_alpha(0.35),
+                            )
+                            .children(selected),
+                        Cond::new(focused && !caret_at_start, caret(), ()),
+                        after,
+                        Cond::new(
+                            buffer.is_empty(),
+                            Element::<NodeBundle>::new()
+                                .named("Input::Placeholder")
+                                .style_dyn(
+                                    |color, ss: &mut StyleBuilder| {
+                                        ss.color(color);
+                                    },
+                                    colors.placeholder,
+                                )
+                                .children(self.placeholder.clone()),
+                            (),
+                        ),
+                    )),
+                IconView::new(
+                    self.trailing_icon.clone(),
+                    tokens.icon_size,
+                    colors.placeholder,
+                ),
+            ))
+    }
+}
+
+pub(crate) fn edit_text_inputs(
+    mut commands: Commands,
+    mut events: EventReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<Focus>,
+    mut clipboard: ResMut<InternalClipboard>,
+    mut inputs: Query<(&mut TextBuffer, &TextInput), Without<Disabled>>,
+) {
+    let Some(Ok((mut buffer, input))) = focus.0.map(|focused| inputs.get_mut(focused)) else {
+        events.clear();
+        return;
+    };
+
+    let ctrl = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let before = buffer.text.clone();
+    let mut submit = false;
+
+    for event in events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Character(c) if ctrl => match c.to_lowercase().as_str() {
+                "a" => buffer.select_all(),
+                "c" => clipboard.0 = buffer.selected_text(),
+                "x" => {
+                    clipboard.0 = buffer.selected_text();
+                    buffer.insert("");
+                }
+                "v" => buffer.insert_within(&clipboard.0, input.max_length),
+                _ => {}
+            },
+            Key::Character(c) => buffer.insert_within(c, input.max_length),
+            Key::Space => buffer.insert_within(" ", input.max_length),
+            Key::Backspace => buffer.backspace(),
+            Key::Delete => buffer.delete(),
+            Key::ArrowLeft => buffer.move_left(shift),
+            Key::ArrowRight => buffer.move_right(shift),
+            Key::ArrowUp if input.multiline => buffer.move_up(shift),
+            Key::ArrowDown if input.multiline => buffer.move_down(shift),
+            // Home and End stay on the caret's line; with Ctrl they go to
+            // the start and end of the text.
+            Key::Home if ctrl => buffer.move_to(0, shift),
+            Key::Home => buffer.move_home(shift),
+            Key::End if ctrl => {
+                let end = buffer.len();
+                buffer.move_to(end, shift);
+            }
+            Key::End => buffer.move_end(shift),
+            Key::Enter if input.multiline && !ctrl => buffer.insert_within("\n", input.max_length),
+            Key::Enter => submit = true,
+            _ => {}
+        }
+    }
+
+    if buffer.text != before {
+        if let Some(on_change) = input.on_change {
+            let text = buffer.text.clone();
+            commands.add(move |world: &mut World| world.run_callback(on_change, text));
+        }
+    }
+
+    if submit {
+        if let Some(on_submit) = input.on_submit {
+            let text = buffer.text.clone();
+            commands.add(move |world: &mut World| world.run_callback(on_submit, text));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        input::keyboard::NativeKeyCode,
+        prelude::{App, Entity, Update},
+    };
+
+    use super::*;
+
+    fn app(input: TextInput) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_event::<KeyboardInput>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Focus>()
+            .init_resource::<InternalClipboard>()
+            .add_systems(Update, edit_text_inputs);
+        let id = app.world_mut().spawn((TextBuffer::new(""), input)).id();
+        app.world_mut().resource_mut::<Focus>().0 = Some(id);
+        (app, id)
+    }
+
+    fn key(app: &mut App, logical_key: Key) {
+        app.world_mut().send_event(KeyboardInput {
+            key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+            logical_key,
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            key(app, Key::Character(c.to_string().into()));
+        }
+    }
+
+    fn hold(app: &mut App, key_code: KeyCode) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key_code);
+    }
+
+    fn release(app: &mut App, key_code: KeyCode) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(key_code);
+    }
+
+    fn buffer(app: &App, id: Entity) -> TextBuffer {
+        app.world().get::<TextBuffer>(id).unwrap().clone()
+    }
+
+    #[test]
+    fn typing_and_backspace() {
+        let (mut app, id) = app(TextInput::default());
+
+        type_text(&mut app, "hi");
+        assert_eq!(buffer(&app, id).text, "hi");
+
+        key(&mut app, Key::Space);
+        type_text(&mut app, "yo");
+        assert_eq!(buffer(&app, id).text, "hi yo");
+
+        key(&mut app, Key::Backspace);
+        assert_eq!(buffer(&app, id).text, "hi y");
+        assert_eq!(buffer(&app, id).caret, 4);
+
+        key(&mut app, Key::ArrowLeft);
+        key(&mut app, Key::Backspace);
+        assert_eq!(buffer(&app, id).text, "hiy");
+        assert_eq!(buffer(&app, id).caret, 2);
+    }
+
+    #[test]
+    fn shift_arrows_select_and_typing_replaces() {
+        let (mut app, id) = app(TextInput::default());
+        type_text(&mut app, "hello");
+
+        hold(&mut app, KeyCode::ShiftLeft);
+        key(&mut app, Key::ArrowLeft);
+        key(&mut app, Key::ArrowLeft);
+        release(&mut app, KeyCode::ShiftLeft);
+        assert_eq!(buffer(&app, id).selected_text(), "lo");
+
+        type_text(&mut app, "p");
+        assert_eq!(buffer(&app, id).text, "help");
+        assert!(!buffer(&app, id).has_selection());
+    }
+
+    #[test]
+    fn cut_copy_and_paste() {
+        let (mut app, id) = app(TextInput::default());
+        type_text(&mut app, "copy");
+
+        hold(&mut app, KeyCode::ControlLeft);
+        key(&mut app, Key::Character("a".into()));
+        key(&mut app, Key::Character("c".into()));
+        release(&mut app, KeyCode::ControlLeft);
+        assert_eq!(app.world().resource::<InternalClipboard>().0, "copy");
+        assert_eq!(buffer(&app, id).text, "copy");
+
+        key(&mut app, Key::End);
+        hold(&mut app, KeyCode::ControlLeft);
+        key(&mut app, Key::Character("v".into()));
+        release(&mut app, KeyCode::ControlLeft);
+        assert_eq!(buffer(&app, id).text, "copycopy");
+
+        hold(&mut app, KeyCode::ControlLeft);
+        key(&mut app, Key::Character("a".into()));
+        key(&mut app, Key::Character("x".into()));
+        release(&mut app, KeyCode::ControlLeft);
+        assert_eq!(buffer(&app, id).text, "");
+        assert_eq!(app.world().resource::<InternalClipboard>().0, "copycopy");
+    }
+
+    #[test]
+    fn unfocused_inputs_ignore_keys() {
+        let (mut app, id) = app(TextInput::default());
+        app.world_mut().resource_mut::<Focus>().0 = None;
+
+        type_text(&mut app, "ignored");
+        assert_eq!(buffer(&app, id).text, "");
+    }
+
+    #[test]
+    fn max_length_clips_insertions_instead_of_the_end() {
+        let (mut app, id) = app(TextInput {
+            max_length: Some(5),
+            ..Default::default()
+        });
+        type_text(&mut app, "abcde");
+
+        // typing in the middle of a full field changes nothing
+        key(&mut app, Key::Home);
+        key(&mut app, Key::ArrowRight);
+        type_text(&mut app, "X");
+        assert_eq!(buffer(&app, id).text, "abcde");
+
+        // pasting only inserts what fits
+        key(&mut app, Key::End);
+        key(&mut app, Key::Backspace);
+        key(&mut app, Key::Backspace);
+        key(&mut app, Key::Home);
+        app.world_mut().resource_mut::<InternalClipboard>().0 = "XYZ".into();
+        hold(&mut app, KeyCode::ControlLeft);
+        key(&mut app, Key::Character("v".into()));
+        release(&mut app, KeyCode::ControlLeft);
+        assert_eq!(buffer(&app, id).text, "XYabc");
+
+        // replacing a selection frees its room
+        hold(&mut app, KeyCode::ShiftLeft);
+        key(&mut app, Key::End);
+        release(&mut app, KeyCode::ShiftLeft);
+        type_text(&mut app, "12345");
+        assert_eq!(buffer(&app, id).text, "XY123");
+    }
+
+    #[test]
+    fn home_and_end_stay_on_the_current_line() {
+        let (mut app, id) = app(TextInput {
+            multiline: true,
+            ..Default::default()
+        });
+        type_text(&mut app, "one");
+        key(&mut app, Key::Enter);
+        type_text(&mut app, "two");
+        key(&mut app, Key::ArrowLeft);
+
+        key(&mut app, Key::Home);
+        assert_eq!(buffer(&app, id).caret, 4);
+        key(&mut app, Key::End);
+        assert_eq!(buffer(&app, id).caret, 7);
+
+        hold(&mut app, KeyCode::ControlLeft);
+        key(&mut app, Key::Home);
+        release(&mut app, KeyCode::ControlLeft);
+        assert_eq!(buffer(&app, id).caret, 0);
+    }
+}
@@ -0,0 +1,755 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    color::{Alpha, Srgba},
+    math::Vec2,
+    prelude::{Children, Component, Entity, GlobalTransform, KeyCode, NodeBundle, Parent, World},
+    ui::{Node, PositionType, ZIndex},
+    window::CursorIcon,
+};
+use bevy_mod_picking::prelude::{Click, Down, ListenerInput, On, Over, Pointer, PointerButton};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderFont, StyleBuilderLayout, StyleHandle, StyleTuple,
+};
+use bevy_quill::{
+    Callback, Cond, Cx, Element, For, IntoViewChild, RunCallback, View, ViewChild, ViewTemplate,
+};
+use bevy_quill_obsidian::{
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+};
+
+use super::{
+    focus_first, input_colors, ButtonSize, FloatAlign, FloatPlacement, FloatSide, Floating,
+    FloatingAutoFocus, FloatingDismiss, Icon, IconView, InputColors, SizeTokens, ThemeMode,
+    UseTheme, FLOATING_Z,
+};
+
+#[derive(Default, Clone, PartialEq)]
+pub struct MenuItem {
+    pub label: String,
+    pub icon: Option<Icon>,
+    pub shortcut: Option<String>,
+    pub disabled: bool,
+    pub separator: bool,
+    pub on_click: Option<Callback>,
+    // Items of a submenu opened by hovering or ArrowRight.
+    pub items: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    pub fn new(label: impl Into<String>) -> MenuItem {
+        MenuItem {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn separator() -> MenuItem {
+        MenuItem {
+            separator: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn on_click(mut self, callback: Callback) -> Self {
+        self.on_click = Some(callback);
+        self
+    }
+
+    pub fn items(mut self, items: Vec<MenuItem>) -> Self {
+        self.items = items;
+        self
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct DropdownMenu {
+    pub open: bool,
+    // The trigger the menu is anchored to.
+    pub children: ViewChild,
+    pub items: Vec<MenuItem>,
+    pub placement: FloatPlacement,
+    pub size: ButtonSize,
+
+    // Requests closing; the owner is expected to clear `open`.
+    pub on_close: Option<Callback<()>>,
+}
+
+impl Default for DropdownMenu {
+    fn default() -> Self {
+        Self {
+            open: false,
+            children: ViewChild::default(),
+            items: Vec::new(),
+            placement: FloatPlacement::new(FloatSide::Bottom, FloatAlign::Start),
+            size: ButtonSize::default(),
+            on_close: None,
+        }
+    }
+}
+
+impl DropdownMenu {
+    pub fn new() -> DropdownMenu {
+        Self::default()
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    pub fn items(mut self, items: Vec<MenuItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn placement(mut self, side: FloatSide, align: FloatAlign) -> Self {
+        self.placement = FloatPlacement::new(side, align);
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn on_close(mut self, callback: Callback<()>) -> Self {
+        self.on_close = Some(callback);
+        self
+    }
+}
+
+impl ViewTemplate for DropdownMenu {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("DropdownMenu")
+            .style(|ss: &mut StyleBuilder| {
+                ss.display(bevy::ui::Display::Flex);
+            })
+            .children((
+                self.children.clone(),
+                Cond::new(
+                    self.open,
+                    MenuList {
+                        items: self.items.clone(),
+                        model: MenuListModel {
+                            anchor: id,
+                            owner: id,
+                            parent: None,
+                            return_focus: Some(id),
+                            on_close: self.on_close,
+                        },
+                        placement: self.placement,
+                        gap: 4.,
+                        depth: 0,
+                        size: self.size,
+                    },
+                    (),
+                ),
+            ))
+    }
+}
+
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+struct ContextMenuState {
+    open: bool,
+    // Where the menu was opened, relative to the context menu area.
+    position: Vec2,
+}
+
+// An area that opens a menu at the pointer when right-clicked.
+#[derive(Default, Clone, PartialEq)]
+pub struct ContextMenu {
+    pub children: ViewChild,
+    pub items: Vec<MenuItem>,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+}
+
+impl ContextMenu {
+    pub fn new() -> ContextMenu {
+        Self::default()
+    }
+
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    pub fn items(mut self, items: Vec<MenuItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for ContextMenu {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let anchor = cx.create_entity();
+        cx.create_effect(
+            move |world: &mut World, _| {
+                if world.get::<ContextMenuState>(id).is_none() {
+                    world.entity_mut(id).insert(ContextMenuState::default());
+                }
+            },
+            (),
+        );
+        let state = cx
+            .use_component::<ContextMenuState>(id)
+            .copied()
+            .unwrap_or_default();
+        let on_close = cx.create_callback(move |world: &mut World| {
+            if let Some(mut state) = world.get_mut::<ContextMenuState>(id) {
+                state.open = false;
+            }
+        });
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("ContextMenu")
+            .style(self.style.clone())
+            .insert_dyn(
+                move |_| {
+                    On::<Pointer<Down>>::run(move |world: &mut World| {
+                        let event = world.resource::<ListenerInput<Pointer<Down>>>();
+                        // Presses on the open menu bubble up here too.
+                        if in_menu(world, event.target, id) {
+                            return;
+                        }
+                        let secondary = event.button == PointerButton::Secondary;
+                        let pointer = event.pointer_location.position;
+                        let origin = match (world.get::<Node>(id), world.get::<GlobalTransform>(id))
+                        {
+                            (Some(node), Some(transform)) => {
+                                transform.translation().truncate() - node.size() / 2.
+                            }
+                            _ => Vec2::ZERO,
+                        };
+                        // Any other press inside the area closes the menu.
+                        if let Some(mut state) = world.get_mut::<ContextMenuState>(id) {
+                            state.open = secondary;
+                            if secondary {
+                                state.position = pointer - origin;
+                            }
+                        }
+                    })
+                },
+                (),
+            )
+            .children((
+                self.children.clone(),
+                Element::<NodeBundle>::for_entity(anchor)
+                    .named("ContextMenu::Anchor")
+                    .style_dyn(
+                        |position: Vec2, ss: &mut StyleBuilder| {
+                            ss.position(PositionType::Absolute)
+                                .left(position.x)
+                                .top(position.y)
+                                .width(0)
+                                .height(0);
+                        },
+                        state.position,
+                    ),
+                Cond::new(
+                    state.open,
+                    MenuList {
+                        items: self.items.clone(),
+                        model: MenuListModel {
+                            anchor,
+                            owner: id,
+                            parent: None,
+                            return_focus: None,
+                            on_close: Some(on_close),
+                        },
+                        placement: FloatPlacement::new(FloatSide::Bottom, FloatAlign::Start),
+                        gap: 0.,
+                        depth: 0,
+                        size: self.size,
+                    },
+                    (),
+                ),
+            ))
+    }
+}
+
+// Props of a menu list, kept on its entity for the event handlers.
+#[derive(Component, Clone, Copy, PartialEq)]
+struct MenuListModel {
+    // Entity the list is positioned against.
+    anchor: Entity,
+    // Presses inside this entity are not outside clicks.
+    owner: Entity,
+    // The list this one is a submenu of.
+    parent: Option<Entity>,
+    // Focus goes back here when the menu closes.
+    return_focus: Option<Entity>,
+    on_close: Option<Callback<()>>,
+}
+
+// Which item's submenu is open.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+struct MenuState {
+    submenu: Option<usize>,
+}
+
+#[derive(Component, Clone, Copy, PartialEq)]
+struct MenuItemModel {
+    list: Entity,
+    index: usize,
+    has_submenu: bool,
+    on_click: Option<Callback>,
+}
+
+#[derive(Clone, PartialEq)]
+struct MenuList {
+    items: Vec<MenuItem>,
+    model: MenuListModel,
+    placement: FloatPlacement,
+    gap: f32,
+    depth: i32,
+    size: ButtonSize,
+}
+
+impl ViewTemplate for MenuList {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        cx.create_effect(
+            move |world: &mut World, _| {
+                if world.get::<MenuState>(id).is_none() {
+                    world.entity_mut(id).insert(MenuState::default());
+                }
+            },
+            (),
+        );
+        let state = cx
+            .use_component::<MenuState>(id)
+            .copied()
+            .unwrap_or_default();
+        let theme = cx.use_theme();
+        let tokens = theme.size(self.size);
+        let colors = input_colors(&theme);
+        // hover:bg-gray-100 dark:hover:bg-gray-800
+        let highlight = if theme.mode == ThemeMode::DARK {
+            theme.colors.gray[8]
+        } else {
+            theme.colors.gray[1]
+        };
+        let on_dismiss = cx.create_callback(move |world: &mut World| {
+            dismiss_list(world, id);
+        });
+
+        let rows: Vec<MenuRow> = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| MenuRow {
+                list: id,
+                index,
+                item: item.clone(),
+                submenu_open: state.submenu == Some(index),
+                depth: self.depth,
+                size: self.size,
+                tokens,
+                colors,
+                highlight,
+                opacity: theme.disabled_opacity(),
+            })
+            .collect();
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Menu")
+            .style_dyn(
+                |(colors, tokens), ss: &mut StyleBuilder| {
+                    ss.position(PositionType::Absolute)
+                        .display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Column)
+                        .min_width(180)
+                        .padding(4)
+                        .border(1)
+                        .border_color(colors.border)
+                        .border_radius(tokens.radius)
+                        .background_color(colors.bg)
+                        .color(colors.text)
+                        .font_size(tokens.font_size);
+                },
+                (colors, tokens),
+            )
+            .insert_dyn(
+                move |(model, placement, gap, depth, on_dismiss)| {
+                    (
+                        model,
+                        AccessibilityNode::from(NodeBuilder::new(Role::Menu)),
+                        Floating {
+                            anchor: model.anchor,
+                            placement,
+                            gap,
+                            match_width: false,
+                        },
+                        FloatingDismiss {
+                            anchor: Some(model.owner),
+                            on_dismiss,
+                        },
+                        FloatingAutoFocus,
+                        // Submenus stack above their parents.
+                        ZIndex::Global(FLOATING_Z + depth),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            let event = world.resource::<ListenerInput<KeyPressEvent>>();
+                            let (target, key) = (event.target, event.key_code);
+                            if handle_key(world, id, target, key) {
+                                world
+                                    .resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .stop_propagation();
+                            }
+                        }),
+                    )
+                },
+                (self.model, self.placement, self.gap, self.depth, on_dismiss),
+            )
+            .children(For::each(rows, |row| row.clone()))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct MenuRow {
+    list: Entity,
+    index: usize,
+    item: MenuItem,
+    submenu_open: bool,
+    depth: i32,
+    size: ButtonSize,
+    tokens: SizeTokens,
+    colors: InputColors,
+    highlight: Srgba,
+    opacity: f32,
+}
+
+impl ViewTemplate for MenuRow {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let item = &self.item;
+        let disabled = item.disabled;
+        let has_submenu = !item.items.is_empty();
+        let highlighted =
+            !disabled && (cx.is_hovered(id) || cx.is_focused(id) || self.submenu_open);
+        let dim = |c: Srgba| {
+            if disabled {
+                c.with_alpha(c.alpha * self.opacity)
+            } else {
+                c
+            }
+        };
+        let (text, muted) = (dim(self.colors.text), dim(self.colors.placeholder));
+        let list = self.list;
+        let index = self.index;
+
+        // Submenus are type-erased, since a menu list contains itself.
+        let submenu = MenuList {
+            items: item.items.clone(),
+            model: MenuListModel {
+                anchor: id,
+                owner: id,
+                parent: Some(self.list),
+                return_focus: Some(id),
+                on_close: None,
+            },
+            placement: FloatPlacement::new(FloatSide::Right, FloatAlign::Start),
+            gap: 4.,
+            depth: self.depth + 1,
+            size: self.size,
+        }
+        .into_view_child();
+
+        Cond::new(
+            item.separator,
+            Element::<NodeBundle>::new()
+                .named("Menu::Separator")
+                .style_dyn(
+                    |color, ss: &mut StyleBuilder| {
+                        ss.height(1).margin((0, 4)).background_color(color);
+                    },
+                    self.colors.border,
+                ),
+            Element::<NodeBundle>::for_entity(id)
+                .named("Menu::Item")
+                .style_dyn(
+                    |(tokens, text, bg, disabled), ss: &mut StyleBuilder| {
+                        ss.display(bevy::ui::Display::Flex)
+                            .flex_direction(bevy::ui::FlexDirection::Row)
+                            .align_items(bevy::ui::AlignItems::Center)
+                            .column_gap(tokens.gap)
+                            .padding((tokens.padding_x, tokens.padding_y))
+                            .border_radius(tokens.radius)
+                            .color(text)
+                            .background_color(bg)
+                            .cursor(if disabled {
+                                CursorIcon::NotAllowed
+                            } else {
+                                CursorIcon::Pointer
+                            });
+                    },
+                    (
+                        self.tokens,
+                        text,
+                        if highlighted {
+                            self.highlight
+                        } else {
+                            Srgba::NONE
+                        },
+                        disabled,
+                    ),
+                )
+                .insert_dyn(
+                    move |(label, disabled, on_click)| {
+                        let mut node = NodeBuilder::new(Role::MenuItem);
+                        node.set_name(label);
+                        if disabled {
+                            node.set_disabled();
+                        }
+                        if has_submenu {
+                            node.set_expanded(false);
+                        }
+
+                        (
+                            AccessibilityNode::from(node),
+                            MenuItemModel {
+                                list,
+                                index,
+                                has_submenu: has_submenu && !disabled,
+                                on_click: if disabled { None } else { on_click },
+                            },
+                            TabIndex(if disabled { -1 } else { 0 }),
+                            On::<Pointer<Over>>::run(move |world: &mut World| {
+                                if disabled {
+                                    return;
+                                }
+                                world.resource_mut::<Focus>().0 = Some(id);
+                                set_submenu(world, list, has_submenu.then_some(index));
+                            }),
+                            On::<Pointer<Click>>::run(move |world: &mut World| {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                    .unwrap();
+                                event.stop_propagation();
+                                activate(world, id);
+                            }),
+                        )
+                    },
+                    (item.label.clone(), disabled, item.on_click),
+                )
+                .children((
+                    IconView::new(item.icon.clone(), self.tokens.icon_size, muted),
+                    Element::<NodeBundle>::new()
+                        .style(|ss: &mut StyleBuilder| {
+                            ss.flex_grow(1.);
+                        })
+                        .children(item.label.clone()),
+                    Cond::new(
+                        item.shortcut.is_some(),
+                        Element::<NodeBundle>::new()
+                            .named("Menu::Shortcut")
+                            .style_dyn(
+                                |(color, size), ss: &mut StyleBuilder| {
+                                    ss.color(color).font_size(size * 0.85);
+                                },
+                                (muted, self.tokens.font_size),
+                            )
+                            .children(item.shortcut.clone().unwrap_or_default()),
+                        (),
+                    ),
+                    Cond::new(
+                        has_submenu,
+                        Element::<NodeBundle>::new()
+                            .named("Menu::SubmenuIndicator")
+                            .style_dyn(
+                                |color, ss: &mut StyleBuilder| {
+                                    ss.color(color);
+                                },
+                                muted,
+                            )
+                            .children(">"),
+                        (),
+                    ),
+                    Cond::new(self.submenu_open && has_submenu, submenu, ()),
+                )),
+        )
+    }
+}
+
+fn set_submenu(world: &mut World, list: Entity, submenu: Option<usize>) {
+    if let Some(mut state) = world.get_mut::<MenuState>(list) {
+        if state.submenu != submenu {
+            state.submenu = submenu;
+        }
+    }
+}
+
+// Whether `entity` is inside a menu list below `root`.
+fn in_menu(world: &World, entity: Entity, root: Entity) -> bool {
+    let mut current = entity;
+    while current != root {
+        if world.get::<MenuListModel>(current).is_some() {
+            return true;
+        }
+        match world.get::<Parent>(current) {
+            Some(parent) => current = parent.get(),
+            None => return false,
+        }
+    }
+    false
+}
+
+// Enabled items of a list, in order; submenu items are not included.
+fn list_items(world: &World, list: Entity) -> Vec<Entity> {
+    world
+        .get::<Children>(list)
+        .map(|children| {
+            children
+                .iter()
+                .copied()
+                .filter(|child| {
+                    world.get::<MenuItemModel>(*child).is_some()
+                        && world.get::<TabIndex>(*child).is_some_and(|i| i.0 >= 0)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Returns whether the key was used.
+fn handle_key(world: &mut World, list: Entity, target: Entity, key: KeyCode) -> bool {
+    let Some(item) = world.get::<MenuItemModel>(target).copied() else {
+        return false;
+    };
+    // Keys from a submenu that it did not use are not ours either.
+    if item.list != list {
+        return false;
+    }
+    let Some(model) = world.get::<MenuListModel>(list).copied() else {
+        return false;
+    };
+
+    let step: isize = match key {
+        KeyCode::ArrowDown => 1,
+        KeyCode::ArrowUp => -1,
+        KeyCode::ArrowRight if item.has_submenu => {
+            set_submenu(world, list, Some(item.index));
+            return true;
+        }
+        KeyCode::ArrowLeft if model.parent.is_some() => {
+            dismiss_list(world, list);
+            return true;
+        }
+        KeyCode::Enter | KeyCode::Space => {
+            activate(world, target);
+            return true;
+        }
+        // The focused item's own list sees Escape first, so an open submenu
+        // closes before its parent.
+        KeyCode::Escape => {
+            dismiss_list(world, list);
+            return true;
+        }
+        _ => return false,
+    };
+
+    let items = list_items(world, list);
+    if let Some(current) = items.iter().position(|entity| *entity == target) {
+        let next = (current as isize + step).rem_euclid(items.len() as isize) as usize;
+        world.resource_mut::<Focus>().0 = Some(items[next]);
+        // Moving away closes an open submenu.
+        set_submenu(world, list, None);
+    }
+    true
+}
+
+// Opens an item's submenu, or runs its action and closes the whole menu.
+fn activate(world: &mut World, item: Entity) {
+    let Some(model) = world.get::<MenuItemModel>(item).copied() else {
+        return;
+    };
+    if model.has_submenu {
+        set_submenu(world, model.list, Some(model.index));
+        return;
+    }
+    if model.on_click.is_none() {
+        return;
+    }
+
+    let mut root = model.list;
+    while let Some(parent) = world.get::<MenuListModel>(root).and_then(|m| m.parent) {
+        root = parent;
+    }
+    dismiss_list(world, root);
+    if let Some(on_click) = model.on_click {
+        world.run_callback(on_click, ());
+    }
+}
+
+// Closes a list: a submenu closes itself and returns focus to its item, the
+// top level list asks its owner to close.
+fn dismiss_list(world: &mut World, list: Entity) {
+    let Some(model) = world.get::<MenuListModel>(list).copied() else {
+        return;
+    };
+    match model.parent {
+        Some(parent) => {
+            set_submenu(world, parent, None);
+            // The submenu hangs off its item.
+            if let Some(item) = world.get::<Parent>(list).map(|p| p.get()) {
+                world.resource_mut::<Focus>().0 = Some(item);
+            }
+        }
+        None => {
+            if let Some(target) = model.return_focus {
+                focus_first(world, target);
+            }
+            if let Some(on_close) = model.on_close {
+                world.run_callback(on_close, ());
+            }
+        }
+    }
+}
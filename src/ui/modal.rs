@@ -0,0 +1,147 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    color::Alpha,
+    math::Vec3,
+    prelude::{NodeBundle, Transform},
+    ui::PositionType,
+};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderRadius, StyleBuilderFont,
+    StyleBuilderLayout, StyleHandle, StyleTuple,
+};
+use bevy_quill::{Callback, Cx, Element, IntoViewChild, View, ViewChild, ViewTemplate};
+
+use super::{Overlay, UseOverlayTransition, UseTheme};
+
+#[derive(Clone, PartialEq)]
+pub struct Modal {
+    pub open: bool,
+    pub children: ViewChild,
+    pub style: StyleHandle,
+    pub close_on_escape: bool,
+    pub close_on_backdrop: bool,
+
+    // Requests closing; the owner is expected to clear `open`.
+    pub on_close: Option<Callback<()>>,
+}
+
+impl Default for Modal {
+    fn default() -> Self {
+        Self {
+            open: false,
+            children: ViewChild::default(),
+            style: StyleHandle::default(),
+            close_on_escape: true,
+            close_on_backdrop: true,
+            on_close: None,
+        }
+    }
+}
+
+impl Modal {
+    pub fn new() -> Modal {
+        Self::default()
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    pub fn close_on_backdrop(mut self, close_on_backdrop: bool) -> Self {
+        self.close_on_backdrop = close_on_backdrop;
+        self
+    }
+
+    pub fn on_close(mut self, callback: Callback<()>) -> Self {
+        self.on_close = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Modal {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let transition = cx.use_overlay_transition(id, self.open);
+        let progress = transition.eased();
+        let theme = cx.use_theme();
+
+        // The modal itself takes no space; its overlay covers the window.
+        Element::<NodeBundle>::for_entity(id)
+            .named("Modal")
+            .style(|ss: &mut StyleBuilder| {
+                ss.position(PositionType::Absolute);
+            })
+            .children(
+                Overlay::new("Modal::Overlay")
+                    .transition(transition)
+                    .close_on_escape(self.close_on_escape)
+                    .close_on_backdrop(self.close_on_backdrop)
+                    .on_close(self.on_close)
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.display(bevy::ui::Display::Flex)
+                            .justify_content(bevy::ui::JustifyContent::Center)
+                            .align_items(bevy::ui::AlignItems::Center);
+                    })
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .named("Modal::Panel")
+                            .style((
+                                |ss: &mut StyleBuilder| {
+                                    ss.display(bevy::ui::Display::Flex)
+                                        .flex_direction(bevy::ui::FlexDirection::Column)
+                                        .gap(12)
+                                        .padding(24)
+                                        .min_width(320)
+                                        .max_width(bevy::ui::Val::Percent(90.))
+                                        .border_radius(8.0);
+                                },
+                                self.style.clone(),
+                            ))
+                            .style_dyn(
+                                |(surface, text, progress), ss: &mut StyleBuilder| {
+                                    ss.background_color(surface.with_alpha(progress))
+                                        .color(text);
+                                },
+                                (theme.surface(), theme.text(), progress),
+                            )
+                            .insert_dyn(
+                                |_| {
+                                    let mut node = NodeBuilder::new(Role::Dialog);
+                                    node.set_modal();
+                                    AccessibilityNode::from(node)
+                                },
+                                (),
+                            )
+                            // zoom in from 95%
+                            .insert_dyn(
+                                |progress: f32| {
+                                    Transform::from_scale(Vec3::splat(0.95 + 0.05 * progress))
+                                },
+                                progress,
+                            )
+                            .children(self.children.clone()),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,466 @@
+use bevy::{
+    a11y::Focus,
+    color::{Alpha, Srgba},
+    core::Name,
+    ecs::system::SystemState,
+    input::ButtonInput,
+    prelude::{
+        default, Added, BuildChildren, Children, Commands, Component, Entity, KeyCode, NodeBundle,
+        Parent, Query, Res, ResMut, Resource, With, Without, World,
+    },
+    time::Time,
+    ui::{PositionType, Style, Val, ZIndex},
+};
+use bevy_mod_picking::prelude::{Click, ListenerInput, On, Pickable, Pointer};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderLayout, StyleHandle, StyleTuple,
+};
+use bevy_quill::{
+    Callback, Cond, Cx, Element, IntoViewChild, Portal, RunCallback, View, ViewChild, ViewTemplate,
+};
+use bevy_quill_obsidian::focus::{KeyPressEvent, TabIndex};
+
+use super::{Floating, ThemeMode, UseTheme};
+
+// Overlays sit above the page but below floating layers, so menus opened
+// inside a modal still show on top of it.
+pub const OVERLAY_Z: i32 = 500;
+
+// Enter and exit transitions take this long.
+const TRANSITION_SECONDS: f32 = 0.15;
+
+// A layer drawn above the page: a full-window `Overlay`, or a floating node
+// such as a select menu. Layers are rendered through a `Portal` and then moved
+// under the shared `OverlayRoot`, so no ancestor can clip them. See
+// `attach_overlay_layers`.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct OverlayLayer;
+
+// Top-level node holding every overlay layer, later layers on top. Spawned
+// the first time a layer shows up.
+#[derive(Resource, Clone, Copy, PartialEq)]
+pub struct OverlayRoot(pub Entity);
+
+// Keeps Tab focus inside this entity while it exists, and gives focus back
+// to whatever had it before once the entity goes away.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct FocusTrap;
+
+// Active traps, innermost last, with the focus to restore for each.
+#[derive(Resource, Default)]
+pub(crate) struct FocusTraps(Vec<(Entity, Option<Entity>)>);
+
+// Enter/exit progress of an overlay, from 0 (closed) to 1 (open). Content
+// stays mounted until the exit transition finishes.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct OverlayTransition {
+    pub open: bool,
+    pub progress: f32,
+}
+
+impl OverlayTransition {
+    pub fn is_visible(&self) -> bool {
+        self.open || self.progress > 0.
+    }
+
+    // Eased progress for styling.
+    pub fn eased(&self) -> f32 {
+        1. - (1. - self.progress).powi(3)
+    }
+}
+
+pub trait UseOverlayTransition {
+    fn use_overlay_transition(&mut self, owner: Entity, open: bool) -> OverlayTransition;
+}
+
+impl<'p, 'w> UseOverlayTransition for Cx<'p, 'w> {
+    fn use_overlay_transition(&mut self, owner: Entity, open: bool) -> OverlayTransition {
+        self.create_effect(
+            move |world: &mut World, open| match world.get_mut::<OverlayTransition>(owner) {
+                Some(mut transition) => {
+                    if transition.open != open {
+                        transition.open = open;
+                    }
+                }
+                None => {
+                    world
+                        .entity_mut(owner)
+                        .insert(OverlayTransition { open, progress: 0. });
+                }
+            },
+            open,
+        );
+        self.use_component::<OverlayTransition>(owner)
+            .copied()
+            .unwrap_or(OverlayTransition { open, progress: 0. })
+    }
+}
+
+// Translucent layer behind a modal overlay.
+#[derive(Clone, PartialEq)]
+pub struct Backdrop {
+    pub opacity: f32,
+    pub on_click: Option<Callback<()>>,
+}
+
+impl ViewTemplate for Backdrop {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let theme = cx.use_theme();
+        // bg-gray-200/75 dark:bg-gray-800/75
+        let color = if theme.mode == ThemeMode::DARK {
+            theme.colors.gray[8]
+        } else {
+            theme.colors.gray[2]
+        };
+
+        Element::<NodeBundle>::new()
+            .named("Backdrop")
+            .style_dyn(
+                |color: Srgba, ss: &mut StyleBuilder| {
+                    ss.position(PositionType::Absolute)
+                        .left(0)
+                        .top(0)
+                        .width(Val::Percent(100.))
+                        .height(Val::Percent(100.))
+                        .background_color(color);
+                },
+                color.with_alpha(0.75 * self.opacity),
+            )
+            .insert_dyn(
+                |on_click| {
+                    On::<Pointer<Click>>::run(move |world: &mut World| {
+                        let mut event = world
+                            .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                            .unwrap();
+                        event.stop_propagation();
+                        if let Some(on_click) = on_click {
+                            world.run_callback(on_click, ());
+                        }
+                    })
+                },
+                self.on_click,
+            )
+    }
+}
+
+// What `Modal` and `Slideover` share: a layer over the window with an optional
+// backdrop, that closes on Escape and, when modal, traps focus.
+#[derive(Clone, PartialEq)]
+pub struct Overlay {
+    pub name: &'static str,
+    pub visible: bool,
+    // Eased transition progress, for the backdrop.
+    pub progress: f32,
+    // A non-modal overlay has no backdrop or focus trap and lets pointer
+    // events through to whatever is underneath.
+    pub modal: bool,
+    pub close_on_escape: bool,
+    pub close_on_backdrop: bool,
+    pub on_close: Option<Callback<()>>,
+    pub style: StyleHandle,
+    pub children: ViewChild,
+}
+
+impl Overlay {
+    pub fn new(name: &'static str) -> Overlay {
+        Self {
+            name,
+            visible: false,
+            progress: 0.,
+            modal: true,
+            close_on_escape: true,
+            close_on_backdrop: true,
+            on_close: None,
+            style: StyleHandle::default(),
+            children: ViewChild::default(),
+        }
+    }
+
+    pub fn transition(mut self, transition: OverlayTransition) -> Self {
+        self.visible = transition.is_visible();
+        self.progress = transition.eased();
+        self
+    }
+
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    pub fn close_on_backdrop(mut self, close_on_backdrop: bool) -> Self {
+        self.close_on_backdrop = close_on_backdrop;
+        self
+    }
+
+    pub fn on_close(mut self, on_close: Option<Callback<()>>) -> Self {
+        self.on_close = on_close;
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Overlay {
+    type View = impl View;
+
+    fn create(&self, _cx: &mut Cx) -> Self::View {
+        let modal = self.modal;
+        let on_close = self.on_close;
+
+        Cond::new(
+            self.visible,
+            Portal::new(
+                Element::<NodeBundle>::new()
+                    .named(self.name)
+                    .style((
+                        |ss: &mut StyleBuilder| {
+                            ss.position(PositionType::Absolute)
+                                .left(0)
+                                .top(0)
+                                .width(Val::Percent(100.))
+                                .height(Val::Percent(100.));
+                        },
+                        self.style.clone(),
+                    ))
+                    .insert_dyn(
+                        move |(on_close, close_on_escape)| {
+                            (
+                                OverlayLayer,
+                                On::<KeyPressEvent>::run(move |world: &mut World| {
+                                    let mut event = world
+                                        .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                        .unwrap();
+                                    if close_on_escape && event.key_code == KeyCode::Escape {
+                                        event.stop_propagation();
+                                        if let Some(on_close) = on_close {
+                                            world.run_callback(on_close, ());
+                                        }
+                                    }
+                                }),
+                            )
+                        },
+                        (on_close, self.close_on_escape),
+                    )
+                    .insert_if(modal, || FocusTrap)
+                    // Let pointer events through to the game view.
+                    .insert_if(!modal, || Pickable::IGNORE)
+                    .children((
+                        Cond::new(
+                            modal,
+                            Backdrop {
+                                opacity: self.progress,
+                                on_click: if self.close_on_backdrop {
+                                    on_close
+                                } else {
+                                    None
+                                },
+                            },
+                            (),
+                        ),
+                        self.children.clone(),
+                    )),
+            ),
+            (),
+        )
+    }
+}
+
+pub(crate) fn animate_overlay_transitions(
+    time: Res<Time>,
+    mut query: Query<&mut OverlayTransition>,
+) {
+    let step = time.delta_seconds() / TRANSITION_SECONDS;
+    for mut transition in query.iter_mut() {
+        let target = if transition.open { 1. } else { 0. };
+        if transition.progress != target {
+            let progress = if target > transition.progress {
+                (transition.progress + step).min(target)
+            } else {
+                (transition.progress - step).max(target)
+            };
+            transition.progress = progress;
+        }
+    }
+}
+
+// Runs before layout, so a layer is never drawn outside the root.
+pub(crate) fn attach_overlay_layers(
+    mut commands: Commands,
+    root: Option<Res<OverlayRoot>>,
+    layers: Query<Entity, (With<OverlayLayer>, Without<Parent>)>,
+) {
+    if layers.is_empty() {
+        return;
+    }
+
+    let root = match root {
+        Some(root) => root.0,
+        None => {
+            let root = commands
+                .spawn((
+                    Name::new("OverlayRoot"),
+                    NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(0.),
+                            top: Val::Px(0.),
+                            width: Val::Percent(100.),
+                            height: Val::Percent(100.),
+                            ..default()
+                        },
+                        z_index: ZIndex::Global(OVERLAY_Z),
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                ))
+                .id();
+            commands.insert_resource(OverlayRoot(root));
+            root
+        }
+    };
+
+    for layer in layers.iter() {
+        commands.entity(root).add_child(layer);
+    }
+}
+
+// Whether `entity` is `ancestor` or below it. A floating layer counts as
+// part of the entity it is anchored to, since it is mounted under the overlay
+// root rather than under its owner.
+fn is_inside(
+    entity: Entity,
+    ancestor: Entity,
+    parents: &Query<&Parent>,
+    floating: &Query<&Floating>,
+) -> bool {
+    let mut current = entity;
+    loop {
+        if current == ancestor {
+            return true;
+        }
+        if let Ok(layer) = floating.get(current) {
+            current = layer.anchor;
+            continue;
+        }
+        match parents.get(current) {
+            Ok(parent) => current = parent.get(),
+            Err(_) => return false,
+        }
+    }
+}
+
+// Tab stops inside `entity`, in tree order.
+pub(crate) fn focusables(
+    entity: Entity,
+    children: &Query<&Children>,
+    tab_indices: &Query<&TabIndex>,
+    out: &mut Vec<Entity>,
+) {
+    if tab_indices.get(entity).is_ok_and(|index| index.0 >= 0) {
+        out.push(entity);
+    }
+    if let Ok(list) = children.get(entity) {
+        for child in list.iter() {
+            focusables(*child, children, tab_indices, out);
+        }
+    }
+}
+
+// `focusables` for code holding the world rather than queries.
+pub(crate) fn tab_stops(world: &mut World, container: Entity) -> Vec<Entity> {
+    let mut state = SystemState::<(Query<&Children>, Query<&TabIndex>)>::new(world);
+    let (children, tab_indices) = state.get(world);
+    let mut stops = Vec::new();
+    focusables(container, &children, &tab_indices, &mut stops);
+    stops
+}
+
+// Runs in `PostUpdate`, after Obsidian has moved focus for Tab during
+// `Update`, so a Tab that leaves the trap is wrapped back in the same frame.
+pub(crate) fn trap_focus(
+    mut focus: ResMut<Focus>,
+    mut traps: ResMut<FocusTraps>,
+    keys: Res<ButtonInput<KeyCode>>,
+    added: Query<Entity, Added<FocusTrap>>,
+    existing: Query<(), With<FocusTrap>>,
+    parents: Query<&Parent>,
+    floating: Query<&Floating>,
+    children: Query<&Children>,
+    tab_indices: Query<&TabIndex>,
+) {
+    // Give focus back when a trap closes.
+    while let Some((trap, previous)) = traps.0.last().copied() {
+        if existing.contains(trap) {
+            break;
+        }
+        traps.0.pop();
+        focus.0 = previous;
+    }
+    traps.0.retain(|(trap, _)| existing.contains(*trap));
+
+    for trap in added.iter() {
+        traps.0.push((trap, focus.0));
+        focus.0 = None;
+    }
+
+    let Some((trap, _)) = traps.0.last().copied() else {
+        return;
+    };
+    if focus
+        .0
+        .is_some_and(|entity| is_inside(entity, trap, &parents, &floating))
+    {
+        return;
+    }
+
+    // Focus left the trap (or has not entered it yet): wrap around.
+    let mut stops = Vec::new();
+    focusables(trap, &children, &tab_indices, &mut stops);
+    let shift = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    focus.0 = Some(if shift {
+        stops.last().copied().unwrap_or(trap)
+    } else {
+        stops.first().copied().unwrap_or(trap)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn layers_share_one_top_level_root() {
+        let mut world = World::new();
+        let first = world.spawn(OverlayLayer).id();
+        world.run_system_once(attach_overlay_layers);
+        let second = world.spawn(OverlayLayer).id();
+        world.run_system_once(attach_overlay_layers);
+
+        let root = world.resource::<OverlayRoot>().0;
+        assert!(world.get::<Parent>(root).is_none());
+        assert_eq!(world.get::<Parent>(first).map(Parent::get), Some(root));
+        assert_eq!(world.get::<Parent>(second).map(Parent::get), Some(root));
+        // Later layers come last, so they draw on top.
+        assert_eq!(
+            world.get::<Children>(root).unwrap().to_vec(),
+            [first, second]
+        );
+    }
+}
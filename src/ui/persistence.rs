@@ -0,0 +1,330 @@
+use std::{fs, io, path::PathBuf};
+
+use bevy::{
+    log::warn,
+    prelude::{Res, ResMut, Resource},
+    tasks::IoTaskPool,
+    time::{Time, Timer, TimerMode},
+};
+
+use super::{QuillUiTheme, ThemeMode};
+
+#[derive(Resource, Clone, PartialEq)]
+pub struct ThemePersistence {
+    pub path: PathBuf,
+}
+
+impl ThemePersistence {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    // `<config dir>/<app_name>/theme.cfg`, falling back to the working
+    // directory when no config dir can be found.
+    pub fn in_config_dir(app_name: &str) -> Self {
+        let dir = config_dir().unwrap_or_default();
+        Self::new(dir.join(app_name).join("theme.cfg"))
+    }
+
+    pub fn load(&self) -> io::Result<ThemeSettings> {
+        fs::read_to_string(&self.path).map(|s| ThemeSettings::parse(&s))
+    }
+
+    // Writes to a temporary file next to the settings and renames it into
+    // place, so a crash mid-write never leaves a truncated file behind.
+    pub fn save(&self, settings: &ThemeSettings) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut temp = self.path.clone().into_os_string();
+        temp.push(".tmp");
+        fs::write(&temp, settings.to_string())?;
+        fs::rename(&temp, &self.path)
+    }
+}
+
+fn config_dir() -> Option<PathBuf> {
+    let env = |key: &str| {
+        std::env::var_os(key)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+
+    if cfg!(target_os = "windows") {
+        env("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        env("HOME").map(|home| home.join("Library").join("Application Support"))
+    } else {
+        env("XDG_CONFIG_HOME").or_else(|| env("HOME").map(|home| home.join(".config")))
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct ThemeSettings {
+    pub mode: Option<ThemeMode>,
+    pub primary: Option<String>,
+    pub gray: Option<String>,
+}
+
+impl ThemeSettings {
+    pub fn from_theme(theme: &QuillUiTheme) -> Self {
+        Self {
+            mode: Some(theme.mode),
+            primary: theme.colors.primary_name.map(String::from),
+            gray: theme.colors.gray_name.map(String::from),
+        }
+    }
+
+    pub fn apply(&self, theme: &mut QuillUiTheme) {
+        if let Some(mode) = self.mode {
+            theme.mode = mode;
+        }
+
+        if let Some(primary) = &self.primary {
+            theme.colors.set_primary(primary);
+        }
+
+        if let Some(gray) = &self.gray {
+            theme.colors.set_gray(gray);
+        }
+    }
+
+    pub fn parse(input: &str) -> Self {
+        let mut settings = Self::default();
+
+        for line in input.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+
+            let value = value.trim();
+            match key.trim() {
+                "mode" => {
+                    settings.mode = match value {
+                        "dark" => Some(ThemeMode::DARK),
+                        "light" => Some(ThemeMode::LIGHT),
+                        _ => None,
+                    }
+                }
+                "primary" => settings.primary = Some(value.to_string()),
+                "gray" => settings.gray = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        settings
+    }
+}
+
+impl std::fmt::Display for ThemeSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(mode) = self.mode {
+            let mode = match mode {
+                ThemeMode::DARK => "dark",
+                ThemeMode::LIGHT => "light",
+            };
+            writeln!(f, "mode={}", mode)?;
+        }
+
+        if let Some(primary) = &self.primary {
+            writeln!(f, "primary={}", primary)?;
+        }
+
+        if let Some(gray) = &self.gray {
+            writeln!(f, "gray={}", gray)?;
+        }
+
+        Ok(())
+    }
+}
+
+// How long the theme has to stay unchanged before it is written to disk.
+const SAVE_DELAY_SECS: f32 = 0.5;
+
+// The settings last loaded from or written to disk, and a change that is
+// waiting for the theme to settle before it is written.
+#[derive(Resource, Default)]
+pub(crate) struct ThemeSaveState {
+    saved: Option<ThemeSettings>,
+    pending: Option<ThemeSettings>,
+    timer: Timer,
+}
+
+pub(crate) fn load_theme_settings(
+    persistence: Res<ThemePersistence>,
+    mut theme: ResMut<QuillUiTheme>,
+    mut state: ResMut<ThemeSaveState>,
+) {
+    match persistence.load() {
+        Ok(settings) => settings.apply(&mut theme),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => warn!("Failed to load theme settings: {}", err),
+    }
+
+    state.saved = Some(ThemeSettings::from_theme(&theme));
+}
+
+pub(crate) fn save_theme_settings(
+    persistence: Res<ThemePersistence>,
+    theme: Res<QuillUiTheme>,
+    time: Res<Time>,
+    mut state: ResMut<ThemeSaveState>,
+) {
+    if theme.is_changed() {
+        let settings = ThemeSettings::from_theme(&theme);
+        if state.saved.as_ref() == Some(&settings) {
+            state.pending = None;
+        } else {
+            state.pending = Some(settings);
+            state.timer = Timer::from_seconds(SAVE_DELAY_SECS, TimerMode::Once);
+        }
+    }
+
+    if state.pending.is_none() || !state.timer.tick(time.delta()).finished() {
+        return;
+    }
+
+    let Some(settings) = state.pending.take() else {
+        return;
+    };
+    state.saved = Some(settings.clone());
+
+    let persistence = persistence.clone();
+    IoTaskPool::get()
+        .spawn(async move {
+            if let Err(err) = persistence.save(&settings) {
+                warn!("Failed to save theme settings: {}", err);
+            }
+        })
+        .detach();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{
+        prelude::{App, PreStartup, Update},
+        time::TimeUpdateStrategy,
+        MinimalPlugins,
+    };
+
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("bevy_quill_demo-{}-{}", name, std::process::id()))
+            .join("theme.cfg")
+    }
+
+    fn app_with(persistence: ThemePersistence) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(persistence)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .init_resource::<QuillUiTheme>()
+            .init_resource::<ThemeSaveState>()
+            .add_systems(PreStartup, load_theme_settings)
+            .add_systems(Update, save_theme_settings);
+        app
+    }
+
+    #[test]
+    fn settings_round_trip_through_a_file() {
+        let persistence = ThemePersistence::new(temp_path("round-trip"));
+        let settings = ThemeSettings {
+            mode: Some(ThemeMode::LIGHT),
+            primary: Some("indigo".into()),
+            gray: Some("stone".into()),
+        };
+
+        persistence.save(&settings).unwrap();
+        assert_eq!(persistence.load().unwrap(), settings);
+
+        let mut theme = QuillUiTheme::default();
+        persistence.load().unwrap().apply(&mut theme);
+        assert_eq!(theme.mode, ThemeMode::LIGHT);
+        assert_eq!(theme.colors.primary, theme.colors.indigo);
+        assert_eq!(theme.colors.gray, theme.colors.stone);
+        assert_eq!(ThemeSettings::from_theme(&theme), settings);
+
+        fs::remove_dir_all(persistence.path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn loading_does_not_rewrite_the_file() {
+        let persistence = ThemePersistence::new(temp_path("no-rewrite"));
+        fs::create_dir_all(persistence.path.parent().unwrap()).unwrap();
+        let contents = "mode=light\nprimary=blue\n# keep\n";
+        fs::write(&persistence.path, contents).unwrap();
+
+        let mut app = app_with(persistence.clone());
+        for _ in 0..10 {
+            app.update();
+        }
+
+        assert_eq!(
+            app.world().resource::<QuillUiTheme>().mode,
+            ThemeMode::LIGHT
+        );
+        assert_eq!(fs::read_to_string(&persistence.path).unwrap(), contents);
+
+        fs::remove_dir_all(persistence.path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn changes_are_written_once_settled() {
+        let persistence = ThemePersistence::new(temp_path("settled"));
+        let _ = fs::remove_dir_all(persistence.path.parent().unwrap());
+
+        let mut app = app_with(persistence.clone());
+        app.update();
+        assert!(!persistence.path.exists());
+
+        app.world_mut().resource_mut::<QuillUiTheme>().mode = ThemeMode::LIGHT;
+        app.update();
+        assert!(!persistence.path.exists());
+
+        for _ in 0..10 {
+            app.update();
+        }
+
+        // The write happens on the IO pool; give it a moment to land.
+        let mut loaded = None;
+        for _ in 0..50 {
+            if let Ok(settings) = persistence.load() {
+                loaded = Some(settings);
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(loaded.unwrap().mode, Some(ThemeMode::LIGHT));
+
+        fs::remove_dir_all(persistence.path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn look_alike_scales_keep_their_name() {
+        let persistence = ThemePersistence::new(temp_path("look-alike"));
+        let mut theme = QuillUiTheme::default();
+        assert_eq!(theme.colors.neutral, theme.colors.zinc);
+        assert!(theme.colors.set_gray("neutral"));
+
+        persistence
+            .save(&ThemeSettings::from_theme(&theme))
+            .unwrap();
+        let loaded = persistence.load().unwrap();
+        assert_eq!(loaded.gray.as_deref(), Some("neutral"));
+
+        let mut restored = QuillUiTheme::default();
+        loaded.apply(&mut restored);
+        assert_eq!(restored.colors.gray_name, Some("neutral"));
+        // Only the settings file is left behind.
+        let files = fs::read_dir(persistence.path.parent().unwrap()).unwrap();
+        assert_eq!(files.count(), 1);
+
+        fs::remove_dir_all(persistence.path.parent().unwrap()).unwrap();
+    }
+}
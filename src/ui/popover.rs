@@ -0,0 +1,213 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::{Component, Entity, KeyCode, NodeBundle, World},
+    ui::{PositionType, ZIndex},
+};
+use bevy_mod_picking::prelude::{ListenerInput, On};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderFont, StyleBuilderLayout, StyleHandle, StyleTuple,
+};
+use bevy_quill::{
+    Callback, Cond, Cx, Element, IntoViewChild, RunCallback, View, ViewChild, ViewTemplate,
+};
+use bevy_quill_obsidian::focus::KeyPressEvent;
+
+use super::{
+    focus_first, focus_step, input_colors, tab_stops, FloatAlign, FloatPlacement, FloatSide,
+    Floating, FloatingAutoFocus, FloatingDismiss, TextInput, UseTheme, FLOATING_Z,
+};
+
+// Props of a popover panel, kept on its entity for the event handlers.
+#[derive(Component, Clone, PartialEq)]
+struct PopoverModel {
+    trigger: Entity,
+    on_close: Option<Callback<()>>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Popover {
+    pub open: bool,
+    // The trigger the panel is anchored to.
+    pub children: ViewChild,
+    pub content: ViewChild,
+    pub placement: FloatPlacement,
+    pub style: StyleHandle,
+
+    // Requests closing; the owner is expected to clear `open`.
+    pub on_close: Option<Callback<()>>,
+}
+
+impl Default for Popover {
+    fn default() -> Self {
+        Self {
+            open: false,
+            children: ViewChild::default(),
+            content: ViewChild::default(),
+            placement: FloatPlacement::new(FloatSide::Bottom, FloatAlign::Start),
+            style: StyleHandle::default(),
+            on_close: None,
+        }
+    }
+}
+
+impl Popover {
+    pub fn new() -> Popover {
+        Self::default()
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    pub fn content(mut self, content: impl IntoViewChild) -> Self {
+        self.content = content.into_view_child();
+        self
+    }
+
+    pub fn placement(mut self, side: FloatSide, align: FloatAlign) -> Self {
+        self.placement = FloatPlacement::new(side, align);
+        self
+    }
+
+    pub fn on_close(mut self, callback: Callback<()>) -> Self {
+        self.on_close = Some(callback);
+        self
+    }
+
+    // Style of the floating panel.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Popover {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let panel = cx.create_entity();
+        let theme = cx.use_theme();
+        let colors = input_colors(&theme);
+        let on_dismiss = cx.create_callback(move |world: &mut World| {
+            close_popover(world, panel);
+        });
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Popover")
+            .style(|ss: &mut StyleBuilder| {
+                ss.display(bevy::ui::Display::Flex);
+            })
+            // Escape from the trigger or the panel. A popover nested in the
+            // panel sees the key first and stops it, so only that one closes.
+            .insert_dyn(
+                move |(open, on_dismiss)| {
+                    On::<KeyPressEvent>::run(move |world: &mut World| {
+                        let mut event = world
+                            .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                            .unwrap();
+                        if open && event.key_code == KeyCode::Escape {
+                            event.stop_propagation();
+                            world.run_callback(on_dismiss, ());
+                        }
+                    })
+                },
+                (self.open, on_dismiss),
+            )
+            .children((
+                self.children.clone(),
+                Cond::new(
+                    self.open,
+                    Element::<NodeBundle>::for_entity(panel)
+                        .named("Popover::Panel")
+                        .style((
+                            |ss: &mut StyleBuilder| {
+                                ss.position(PositionType::Absolute)
+                                    .display(bevy::ui::Display::Flex)
+                                    .flex_direction(bevy::ui::FlexDirection::Column)
+                                    .gap(8)
+                                    .padding(12)
+                                    .border(1)
+                                    .border_radius(6.0);
+                            },
+                            self.style.clone(),
+                        ))
+                        .style_dyn(
+                            |(bg, border, text), ss: &mut StyleBuilder| {
+                                ss.background_color(bg).border_color(border).color(text);
+                            },
+                            (colors.bg, colors.border, colors.text),
+                        )
+                        .insert_dyn(
+                            move |(placement, on_close, on_dismiss)| {
+                                (
+                                    PopoverModel {
+                                        trigger: id,
+                                        on_close,
+                                    },
+                                    AccessibilityNode::from(NodeBuilder::new(Role::Dialog)),
+                                    Floating {
+                                        anchor: id,
+                                        placement,
+                                        gap: 4.,
+                                        match_width: false,
+                                    },
+                                    FloatingDismiss {
+                                        anchor: Some(id),
+                                        on_dismiss,
+                                    },
+                                    FloatingAutoFocus,
+                                    ZIndex::Global(FLOATING_Z),
+                                    On::<KeyPressEvent>::run(move |world: &mut World| {
+                                        let event =
+                                            world.resource::<ListenerInput<KeyPressEvent>>();
+                                        let (target, key) = (event.target, event.key_code);
+                                        // Text fields use the arrow keys themselves.
+                                        if world.get::<TextInput>(target).is_some() {
+                                            return;
+                                        }
+                                        let step = match key {
+                                            KeyCode::ArrowDown | KeyCode::ArrowRight => 1,
+                                            KeyCode::ArrowUp | KeyCode::ArrowLeft => -1,
+                                            _ => return,
+                                        };
+                                        world
+                                            .resource_mut::<ListenerInput<KeyPressEvent>>()
+                                            .stop_propagation();
+                                        focus_step(world, panel, step);
+                                    }),
+                                )
+                            },
+                            (self.placement, self.on_close, on_dismiss),
+                        )
+                        .children(self.content.clone()),
+                    (),
+                ),
+            ))
+    }
+}
+
+// Closes on Escape or an outside click, returning focus to the trigger if
+// it was inside the panel.
+fn close_popover(world: &mut World, panel: Entity) {
+    let Some(model) = world.get::<PopoverModel>(panel).cloned() else {
+        return;
+    };
+    let focused = world.resource::<Focus>().0;
+    if focused.is_some_and(|entity| tab_stops(world, panel).contains(&entity)) {
+        focus_first(world, model.trigger);
+    }
+    if let Some(on_close) = model.on_close {
+        world.run_callback(on_close, ());
+    }
+}
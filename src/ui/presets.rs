@@ -0,0 +1,165 @@
+use bevy::color::Srgba;
+
+use super::{QuillUiTheme, ThemeMode, ThemeTokens};
+
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum ThemePreset {
+    #[default]
+    Default,
+    HighContrastDark,
+    HighContrastLight,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl QuillUiTheme {
+    // Presets only pick from the named scales, so the chosen primary can be
+    // persisted and restored by name.
+    pub fn preset(preset: ThemePreset) -> QuillUiTheme {
+        let mut theme = QuillUiTheme::default();
+
+        match preset {
+            ThemePreset::Default => {}
+            ThemePreset::HighContrastDark => {
+                theme.mode = ThemeMode::DARK;
+                theme.colors.set_primary("yellow");
+                theme.colors.set_gray("neutral");
+                theme.tokens = ThemeTokens {
+                    background: Some(Srgba::BLACK),
+                    surface: Some(theme.colors.neutral[9]),
+                    text: Some(Srgba::WHITE),
+                    ..Default::default()
+                };
+            }
+            ThemePreset::HighContrastLight => {
+                theme.mode = ThemeMode::LIGHT;
+                theme.colors.set_primary("blue");
+                theme.colors.set_gray("neutral");
+                theme.tokens = ThemeTokens {
+                    background: Some(Srgba::WHITE),
+                    surface: Some(Srgba::WHITE),
+                    text: Some(Srgba::BLACK),
+                    ..Default::default()
+                };
+            }
+            // Red and green look alike with weak green cones. Move primary and
+            // "green" onto blues and teals, and the red end onto orange, so
+            // paired colors differ along the blue/orange axis.
+            ThemePreset::Deuteranopia => {
+                theme.colors.set_primary("blue");
+                theme.colors.green = theme.colors.teal;
+                theme.colors.rose = theme.colors.orange;
+            }
+            // Weak red cones also make reds look dark, so the red end moves
+            // to yellow, which stays bright, and "green" onto sky blue.
+            ThemePreset::Protanopia => {
+                theme.colors.set_primary("blue");
+                theme.colors.green = theme.colors.sky;
+                theme.colors.rose = theme.colors.yellow;
+            }
+            // Blue-yellow deficiency: lean on the red/green axis, and move
+            // yellow, which washes out to pink, onto orange.
+            ThemePreset::Tritanopia => {
+                theme.colors.set_primary("rose");
+                theme.colors.yellow = theme.colors.orange;
+            }
+        }
+
+        theme
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{Contrast, WCAG_AAA};
+
+    const PRESETS: [ThemePreset; 6] = [
+        ThemePreset::Default,
+        ThemePreset::HighContrastDark,
+        ThemePreset::HighContrastLight,
+        ThemePreset::Deuteranopia,
+        ThemePreset::Protanopia,
+        ThemePreset::Tritanopia,
+    ];
+
+    #[test]
+    fn presets_meet_minimum_contrast() {
+        for preset in PRESETS {
+            let report = QuillUiTheme::preset(preset).validate();
+            assert!(report.is_ok(), "{:?}: {:#?}", preset, report.failures);
+        }
+    }
+
+    #[test]
+    fn presets_meet_minimum_contrast_in_light_mode() {
+        for preset in [
+            ThemePreset::Default,
+            ThemePreset::Deuteranopia,
+            ThemePreset::Protanopia,
+            ThemePreset::Tritanopia,
+        ] {
+            let mut theme = QuillUiTheme::preset(preset);
+            theme.mode = ThemeMode::LIGHT;
+            let report = theme.validate();
+            assert!(report.is_ok(), "{:?}: {:#?}", preset, report.failures);
+        }
+    }
+
+    #[test]
+    fn high_contrast_presets_meet_aaa() {
+        for (preset, mode) in [
+            (ThemePreset::HighContrastDark, ThemeMode::DARK),
+            (ThemePreset::HighContrastLight, ThemeMode::LIGHT),
+        ] {
+            let theme = QuillUiTheme::preset(preset);
+            assert_eq!(theme.mode, mode);
+            for (name, background) in [
+                ("background", theme.background()),
+                ("surface", theme.surface()),
+            ] {
+                let ratio = theme.text().contrast_ratio(background);
+                assert!(
+                    ratio >= WCAG_AAA,
+                    "{:?}: text on {} is {:.2}:1",
+                    preset,
+                    name,
+                    ratio
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn color_blind_presets_remap_confusable_scales() {
+        let default = QuillUiTheme::default();
+        let deuteranopia = QuillUiTheme::preset(ThemePreset::Deuteranopia);
+        let protanopia = QuillUiTheme::preset(ThemePreset::Protanopia);
+        let tritanopia = QuillUiTheme::preset(ThemePreset::Tritanopia);
+
+        assert!(deuteranopia != protanopia);
+        for theme in [&deuteranopia, &protanopia] {
+            assert_ne!(theme.colors.green, default.colors.green);
+            assert_ne!(theme.colors.rose, default.colors.rose);
+            // No two button colors collapse into one.
+            assert_ne!(theme.colors.green, theme.colors.blue);
+            assert_ne!(theme.colors.green, theme.colors.indigo);
+        }
+        assert_ne!(tritanopia.colors.yellow, default.colors.yellow);
+        assert_eq!(tritanopia.colors.primary, default.colors.rose);
+    }
+
+    #[test]
+    fn presets_pick_primary_from_named_scales() {
+        for preset in PRESETS {
+            let theme = QuillUiTheme::preset(preset);
+            let primary = theme.colors.primary_name;
+            assert!(primary.is_some(), "{:?} has an unnamed primary", preset);
+            assert_eq!(
+                theme.colors.by_name(primary.unwrap()),
+                Some(theme.colors.primary)
+            );
+        }
+    }
+}
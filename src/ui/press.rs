@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use bevy::{
+    a11y::Focus,
+    prelude::{Component, Entity, Has, World},
+    time::Time,
+};
+use bevy_mod_picking::prelude::{Click, ListenerInput, On, Pointer};
+use bevy_quill::{Callback, Cx, RunCallback};
+use bevy_quill_obsidian::controls::{Disabled, IsDisabled};
+
+// Delay before auto-repeat kicks in, and the interval between repeats.
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+const REPEAT_INTERVAL: Duration = Duration::from_millis(80);
+
+// Two clicks closer together than this count as a double click.
+pub const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(300);
+
+// Callbacks fired while an entity is held down.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct Pressable {
+    pub on_click: Option<Callback>,
+    pub on_long_press: Option<Callback>,
+    pub long_press_duration: Duration,
+    pub repeat: bool,
+    // A loading entity ignores presses, just like a disabled one.
+    pub loading: bool,
+}
+
+// Present while the pointer is held down on an entity.
+#[derive(Component, Clone, Copy, PartialEq)]
+pub struct Pressed {
+    pub since: Duration,
+    pub long_press_fired: bool,
+    pub last_repeat: Option<Duration>,
+}
+
+impl Pressed {
+    pub fn new(now: Duration) -> Self {
+        Self {
+            since: now,
+            long_press_fired: false,
+            last_repeat: None,
+        }
+    }
+
+    // A press that already fired a long press or a repeat swallows the click.
+    pub fn consumed(&self) -> bool {
+        self.long_press_fired || self.last_repeat.is_some()
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq)]
+pub(crate) struct SuppressClick;
+
+#[derive(Component, Clone, Copy, PartialEq)]
+pub(crate) struct LastClick(pub Duration);
+
+pub trait UseIsPressed {
+    fn is_pressed(&mut self, target: Entity) -> bool;
+}
+
+impl<'p, 'w> UseIsPressed for Cx<'p, 'w> {
+    fn is_pressed(&mut self, target: Entity) -> bool {
+        self.use_component::<Pressed>(target).is_some()
+    }
+}
+
+// Click handler shared by focusable controls. Clicks on a disabled control are
+// ignored outright; otherwise the control takes focus, the click stops here and
+// `action` runs.
+pub(crate) fn click_to_focus(
+    id: Entity,
+    action: impl Fn(&mut World) + Send + Sync + 'static,
+) -> On<Pointer<Click>> {
+    On::<Pointer<Click>>::run(move |world: &mut World| {
+        if world.is_disabled(id) {
+            return;
+        }
+        world.resource_mut::<Focus>().0 = Some(id);
+        world
+            .resource_mut::<ListenerInput<Pointer<Click>>>()
+            .stop_propagation();
+        action(world);
+    })
+}
+
+pub(crate) fn update_pressed(world: &mut World) {
+    let now = world.resource::<Time>().elapsed();
+    let mut callbacks = Vec::new();
+    let mut cancelled = Vec::new();
+
+    let mut query = world.query::<(Entity, &mut Pressed, &Pressable, Has<Disabled>)>();
+    for (entity, mut pressed, pressable, disabled) in query.iter_mut(world) {
+        // Became disabled or started loading while held: drop the press.
+        if disabled || pressable.loading {
+            cancelled.push(entity);
+            continue;
+        }
+
+        let held = now.saturating_sub(pressed.since);
+
+        if let Some(on_long_press) = pressable.on_long_press {
+            if !pressed.long_press_fired && held >= pressable.long_press_duration {
+                pressed.long_press_fired = true;
+                callbacks.push(on_long_press);
+            }
+        }
+
+        if let (true, Some(on_click)) = (pressable.repeat, pressable.on_click) {
+            let due = match pressed.last_repeat {
+                Some(last) => now.saturating_sub(last) >= REPEAT_INTERVAL,
+                None => held >= REPEAT_DELAY,
+            };
+            if due {
+                pressed.last_repeat = Some(now);
+                callbacks.push(on_click);
+            }
+        }
+    }
+
+    for entity in cancelled {
+        world.entity_mut(entity).remove::<Pressed>();
+    }
+
+    for callback in callbacks {
+        world.run_callback(callback, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn still_pressed(disabled: bool, loading: bool) -> bool {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let mut entity = world.spawn((
+            Pressed::new(Duration::ZERO),
+            Pressable {
+                loading,
+                ..Default::default()
+            },
+        ));
+        if disabled {
+            entity.insert(Disabled);
+        }
+        let id = entity.id();
+
+        update_pressed(&mut world);
+        world.get::<Pressed>(id).is_some()
+    }
+
+    #[test]
+    fn press_ends_when_disabled_or_loading() {
+        assert!(still_pressed(false, false));
+        assert!(!still_pressed(true, false));
+        assert!(!still_pressed(false, true));
+    }
+}
@@ -0,0 +1,503 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role, Toggled},
+        AccessibilityNode, Focus,
+    },
+    color::Color,
+    prelude::{Children, Component, Entity, KeyCode, NodeBundle, World},
+    window::CursorIcon,
+};
+use bevy_mod_picking::prelude::{ListenerInput, On};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderFont, StyleBuilderLayout, StyleBuilderOutline, StyleHandle, StyleTuple,
+};
+use bevy_quill::{
+    Callback, Cond, Cx, Element, For, IntoViewChild, RunCallback, View, ViewChild, ViewTemplate,
+};
+use bevy_quill_obsidian::{
+    controls::{Disabled, IsDisabled},
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+};
+
+use super::{button_ring_color, click_to_focus, control_colors, ButtonColor, ButtonSize, UseTheme};
+
+// Selection state shared with the radios inside a `RadioGroup`.
+#[derive(Component, Default, Clone, PartialEq)]
+pub struct RadioGroupContext {
+    pub selected: Option<String>,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub disabled: bool,
+    pub on_change: Option<Callback<String>>,
+}
+
+// The value of a radio entity, used for arrow key navigation.
+#[derive(Component, Clone, PartialEq)]
+pub struct RadioValue(pub String);
+
+#[derive(Default, Clone, PartialEq)]
+pub struct Radio {
+    pub label: ViewChild,
+    pub value: String,
+    pub checked: bool,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+    pub disabled: bool,
+    pub tab_index: i32,
+
+    // Fires with `true` when the radio is selected.
+    pub on_change: Option<Callback<bool>>,
+}
+
+impl Radio {
+    pub fn new() -> Radio {
+        Self::default()
+    }
+
+    pub fn label(mut self, label: impl IntoViewChild) -> Self {
+        self.label = label.into_view_child();
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<bool>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Radio {
+    type View = impl View;
+
+    fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
+        let id = cx.create_entity();
+        let group = cx.use_inherited_component::<RadioGroupContext>().cloned();
+        let (checked, color, size, disabled, select) = match group {
+            Some(group) => (
+                group.selected.as_ref() == Some(&self.value),
+                group.color,
+                group.size,
+                group.disabled || self.disabled,
+                group
+                    .on_change
+                    .map(|on_change| (on_change, self.value.clone())),
+            ),
+            None => (self.checked, self.color, self.size, self.disabled, None),
+        };
+        let on_change = self.on_change;
+        let hovering = cx.is_hovered(id) && !disabled;
+        let focused = cx.is_focus_visible(id);
+        let theme = cx.use_theme();
+        let tokens = theme.size(size);
+        let colors = control_colors(color, theme.clone(), checked, hovering, disabled);
+        let circle = tokens.font_size;
+        let ring = button_ring_color(color, theme.clone());
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Radio")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Row)
+                        .align_items(bevy::ui::AlignItems::Center);
+                },
+                self.style.clone(),
+            ))
+            .style_dyn(
+                |(tokens, text, disabled), ss| {
+                    ss.column_gap(tokens.gap)
+                        .font_size(tokens.font_size)
+                        .color(text)
+                        .cursor(if disabled {
+                            CursorIcon::NotAllowed
+                        } else {
+                            CursorIcon::Pointer
+                        });
+                },
+                (tokens, theme.text(), disabled),
+            )
+            .insert_if(disabled, || Disabled)
+            .insert_dyn(
+                move |(value, checked, disabled, tab_index, on_change, select)| {
+                    let mut node = NodeBuilder::new(Role::RadioButton);
+                    node.set_toggled(if checked {
+                        Toggled::True
+                    } else {
+                        Toggled::False
+                    });
+                    if disabled {
+                        node.set_disabled();
+                    }
+
+                    let choose = move |world: &mut World| {
+                        if checked {
+                            return;
+                        }
+                        if let Some(on_change) = on_change {
+                            world.run_callback(on_change, true);
+                        }
+                        if let Some((on_select, value)) = &select {
+                            world.run_callback(*on_select, value.clone());
+                        }
+                    };
+                    let choose_click = choose.clone();
+
+                    (
+                        AccessibilityNode::from(node),
+                        RadioValue(value),
+                        // Only the checked radio of a group is a tab stop.
+                        TabIndex(if disabled { -1 } else { tab_index }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .unwrap();
+                                if !event.repeat && event.key_code == KeyCode::Space {
+                                    event.stop_propagation();
+                                    choose(world);
+                                }
+                            }
+                        }),
+                        click_to_focus(id, choose_click),
+                    )
+                },
+                (
+                    self.value.clone(),
+                    checked,
+                    disabled,
+                    self.tab_index,
+                    on_change,
+                    select,
+                ),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("Radio::Circle")
+                    .style_dyn(
+                        |(size, colors, focused, ring), ss: &mut StyleBuilder| {
+                            ss.display(bevy::ui::Display::Flex)
+                                .justify_content(bevy::ui::JustifyContent::Center)
+                                .align_items(bevy::ui::AlignItems::Center)
+                                .flex_shrink(0.)
+                                .width(size)
+                                .height(size)
+                                .border(1)
+                                .border_radius(size / 2.)
+                                .border_color(colors.border)
+                                .background_color(colors.fill);
+                            if focused {
+                                ss.outline_color(ring).outline_width(2).outline_offset(2);
+                            } else {
+                                ss.outline_color(Color::NONE);
+                            }
+                        },
+                        (circle, colors, focused, ring),
+                    )
+                    .children(Cond::new(
+                        checked,
+                        Element::<NodeBundle>::new().style_dyn(
+                            |(size, color), ss: &mut StyleBuilder| {
+                                ss.width(size * 0.4)
+                                    .height(size * 0.4)
+                                    .border_radius(size * 0.2)
+                                    .background_color(color);
+                            },
+                            (circle, colors.mark),
+                        ),
+                        (),
+                    )),
+                self.label.clone(),
+            ))
+    }
+}
+
+#[derive(Default, Clone, PartialEq)]
+pub struct RadioGroup {
+    // (value, label) pairs, in navigation order.
+    pub options: Vec<(String, String)>,
+    pub selected: Option<String>,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+    pub disabled: bool,
+
+    pub on_change: Option<Callback<String>>,
+}
+
+impl RadioGroup {
+    pub fn new() -> RadioGroup {
+        Self::default()
+    }
+
+    pub fn option(mut self, value: impl Into<String>, label: impl Into<String>) -> Self {
+        self.options.push((value.into(), label.into()));
+        self
+    }
+
+    pub fn selected(mut self, selected: Option<impl Into<String>>) -> Self {
+        self.selected = selected.map(Into::into);
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<String>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for RadioGroup {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        cx.insert(RadioGroupContext {
+            selected: self.selected.clone(),
+            color: self.color,
+            size: self.size,
+            disabled: self.disabled,
+            on_change: self.on_change,
+        });
+
+        let selected = self.selected.clone();
+        let tab_stop = selected
+            .clone()
+            .or_else(|| self.options.first().map(|(value, _)| value.clone()));
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("RadioGroup")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Column)
+                        .row_gap(8);
+                },
+                self.style.clone(),
+            ))
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(
+                move |(options, selected, on_change)| {
+                    let mut node = NodeBuilder::new(Role::RadioGroup);
+                    node.set_name("Radio group");
+
+                    (
+                        AccessibilityNode::from(node),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .unwrap();
+                            let step: isize = match event.key_code {
+                                KeyCode::ArrowDown | KeyCode::ArrowRight => 1,
+                                KeyCode::ArrowUp | KeyCode::ArrowLeft => -1,
+                                _ => return,
+                            };
+                            event.stop_propagation();
+                            if world.is_disabled(id) || options.is_empty() {
+                                return;
+                            }
+
+                            // With nothing selected yet, move on from the
+                            // focused radio.
+                            let focused = world
+                                .resource::<Focus>()
+                                .0
+                                .and_then(|entity| world.get::<RadioValue>(entity))
+                                .map(|value| value.0.clone());
+                            let current =
+                                selected.as_ref().or(focused.as_ref()).and_then(|current| {
+                                    options.iter().position(|(value, _)| value == current)
+                                });
+                            let next = match current {
+                                Some(index) => (index as isize + step)
+                                    .rem_euclid(options.len() as isize)
+                                    as usize,
+                                None if step < 0 => options.len() - 1,
+                                None => 0,
+                            };
+                            let value = options[next].0.clone();
+
+                            // Move focus along with the selection.
+                            if let Some(radio) = radio_entity(world, id, &value) {
+                                world.resource_mut::<Focus>().0 = Some(radio);
+                            }
+
+                            if let Some(on_change) = on_change {
+                                world.run_callback(on_change, value);
+                            }
+                        }),
+                    )
+                },
+                (self.options.clone(), selected, self.on_change),
+            )
+            .children(For::each(self.options.clone(), move |(value, label)| {
+                Radio::new()
+                    .value(value.clone())
+                    .label(label.clone())
+                    .tab_index(if tab_stop.as_ref() == Some(value) {
+                        0
+                    } else {
+                        -1
+                    })
+            }))
+    }
+}
+
+fn radio_entity(world: &World, group: Entity, value: &str) -> Option<Entity> {
+    world.get::<Children>(group).and_then(|children| {
+        children
+            .iter()
+            .copied()
+            .find(|child| world.get::<RadioValue>(*child).map(|v| v.0.as_str()) == Some(value))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        input::keyboard::Key,
+        prelude::{App, In, ResMut, Resource},
+    };
+
+    use super::*;
+    use crate::ui::testing::{headless_app, named, spawn_view, tap_key};
+
+    #[derive(Resource, Default)]
+    struct Selected(Option<String>);
+
+    #[derive(Clone, PartialEq)]
+    struct GroupView {
+        disabled: bool,
+    }
+
+    impl ViewTemplate for GroupView {
+        type View = impl View;
+
+        fn create(&self, cx: &mut Cx) -> Self::View {
+            let selected = cx.use_resource::<Selected>().0.clone();
+            let on_change =
+                cx.create_callback(|value: In<String>, mut selected: ResMut<Selected>| {
+                    selected.0 = Some(value.0);
+                });
+
+            RadioGroup::new()
+                .option("a", "A")
+                .option("b", "B")
+                .option("c", "C")
+                .selected(selected)
+                .disabled(self.disabled)
+                .on_change(on_change)
+        }
+    }
+
+    fn group(disabled: bool) -> (App, Vec<Entity>) {
+        let mut app = headless_app();
+        app.init_resource::<Selected>();
+        spawn_view(&mut app, GroupView { disabled });
+        let radios = named(&mut app, "Radio");
+        assert_eq!(radios.len(), 3);
+        (app, radios)
+    }
+
+    fn selected(app: &App) -> Option<&str> {
+        app.world().resource::<Selected>().0.as_deref()
+    }
+
+    fn focused(app: &App) -> Option<Entity> {
+        app.world().resource::<Focus>().0
+    }
+
+    #[test]
+    fn arrows_move_on_from_the_focused_radio() {
+        let (mut app, radios) = group(false);
+        app.world_mut().resource_mut::<Focus>().0 = Some(radios[0]);
+
+        // Nothing is selected yet: the first arrow moves on from "a".
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(selected(&app), Some("b"));
+        assert_eq!(focused(&app), Some(radios[1]));
+
+        tap_key(&mut app, KeyCode::ArrowUp, Key::ArrowUp);
+        assert_eq!(selected(&app), Some("a"));
+
+        // And wraps around at either end.
+        tap_key(&mut app, KeyCode::ArrowLeft, Key::ArrowLeft);
+        assert_eq!(selected(&app), Some("c"));
+        assert_eq!(focused(&app), Some(radios[2]));
+    }
+
+    #[test]
+    fn space_selects_the_focused_radio() {
+        let (mut app, radios) = group(false);
+        app.world_mut().resource_mut::<Focus>().0 = Some(radios[1]);
+
+        tap_key(&mut app, KeyCode::Space, Key::Space);
+        assert_eq!(selected(&app), Some("b"));
+    }
+
+    #[test]
+    fn disabled_group_ignores_keys() {
+        let (mut app, radios) = group(true);
+        app.world_mut().resource_mut::<Focus>().0 = Some(radios[0]);
+
+        tap_key(&mut app, KeyCode::Space, Key::Space);
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(selected(&app), None);
+    }
+}
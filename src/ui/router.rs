@@ -0,0 +1,32 @@
+use bevy::prelude::Resource;
+
+// The app's current route, e.g. "/settings". Buttons with a `to` target set it
+// when clicked and render as active while it matches. Starts at "/".
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct ActiveRoute(pub String);
+
+impl Default for ActiveRoute {
+    fn default() -> Self {
+        Self::new("/")
+    }
+}
+
+impl ActiveRoute {
+    pub fn new(route: impl Into<String>) -> Self {
+        Self(route.into())
+    }
+
+    pub fn matches(&self, to: &str) -> bool {
+        self.0 == to
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn home_route_is_active_by_default() {
+        assert!(ActiveRoute::default().matches("/"));
+    }
+}
@@ -0,0 +1,930 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    color::{Alpha, Srgba},
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonInput, ButtonState,
+    },
+    prelude::{
+        Commands, Component, Entity, EventReader, In, KeyCode, NodeBundle, Quat, Query, Res,
+        Transform, World,
+    },
+    time::Time,
+    ui::{UiRect, Val},
+    window::CursorIcon,
+};
+use bevy_mod_picking::prelude::{Click, ListenerInput, On, Over, Pointer};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderFont, StyleBuilderLayout, StyleHandle, StyleTuple,
+};
+use bevy_quill::{Callback, Cond, Element, For, Portal, RunCallback, View, ViewTemplate};
+use bevy_quill_obsidian::{
+    controls::{Disabled, IsDisabled, ScrollView},
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::UseIsFocus,
+};
+
+use super::{
+    button_ring_color, click_to_focus, input_colors, ButtonColor, ButtonSize, FloatPlacement,
+    Floating, FloatingDismiss, Input, OverlayLayer, SizeTokens, UseTheme,
+};
+
+// Keystrokes further apart than this start a new type-ahead search.
+const TYPEAHEAD_TIMEOUT: f32 = 0.5;
+
+// Tallest the option list grows before it scrolls.
+const MENU_MAX_HEIGHT: f32 = 240.0;
+
+// Props of a `Select`, kept on its entity for the event handlers.
+#[derive(Component, Clone, PartialEq)]
+struct SelectModel {
+    trigger: Entity,
+    options: Vec<(String, String)>,
+    selected: Vec<String>,
+    multiple: bool,
+    searchable: bool,
+    on_change: Option<Callback<Vec<String>>>,
+}
+
+impl SelectModel {
+    // Options whose label contains `query`, ignoring case.
+    fn filtered(&self, query: &str) -> Vec<(String, String)> {
+        let query = query.to_lowercase();
+        self.options
+            .iter()
+            .filter(|(_, label)| label.to_lowercase().contains(&query))
+            .cloned()
+            .collect()
+    }
+}
+
+#[derive(Component, Default, Clone, PartialEq)]
+pub struct SelectState {
+    pub open: bool,
+    // Index into the filtered options.
+    pub highlighted: Option<usize>,
+    pub query: String,
+    typeahead: String,
+    typed_at: f32,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Select {
+    // (value, label) pairs, in display order.
+    pub options: Vec<(String, String)>,
+    pub selected: Vec<String>,
+    pub placeholder: String,
+    pub multiple: bool,
+    pub searchable: bool,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+    pub disabled: bool,
+    pub tab_index: i32,
+
+    // Receives every selected value; a single value unless `multiple` is set.
+    pub on_change: Option<Callback<Vec<String>>>,
+}
+
+impl Default for Select {
+    fn default() -> Self {
+        Self {
+            options: Vec::new(),
+            selected: Vec::new(),
+            placeholder: "Select...".to_string(),
+            multiple: false,
+            searchable: false,
+            color: ButtonColor::default(),
+            size: ButtonSize::default(),
+            style: StyleHandle::default(),
+            disabled: false,
+            tab_index: 0,
+            on_change: None,
+        }
+    }
+}
+
+impl Select {
+    pub fn new() -> Select {
+        Self::default()
+    }
+
+    pub fn option(mut self, value: impl Into<String>, label: impl Into<String>) -> Self {
+        self.options.push((value.into(), label.into()));
+        self
+    }
+
+    pub fn selected(mut self, selected: Vec<String>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<Vec<String>>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Select {
+    type View = impl View;
+
+    fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
+        let id = cx.create_entity();
+        let trigger = cx.create_entity();
+        cx.create_effect(
+            move |world: &mut World, _| {
+                if world.get::<SelectState>(id).is_none() {
+                    world.entity_mut(id).insert(SelectState::default());
+                }
+            },
+            (),
+        );
+        let state = cx
+            .use_component::<SelectState>(id)
+            .cloned()
+            .unwrap_or_default();
+
+        let disabled = self.disabled;
+        let open = state.open && !disabled;
+        let focused = (cx.is_focus_visible(trigger) || open) && !disabled;
+        let theme = cx.use_theme();
+        let tokens = theme.size(self.size);
+        let colors = input_colors(&theme);
+        let ring = button_ring_color(self.color, theme.clone());
+        let highlight = ring.with_alpha(0.15);
+
+        let model = SelectModel {
+            trigger,
+            options: self.options.clone(),
+            selected: self.selected.clone(),
+            multiple: self.multiple,
+            searchable: self.searchable,
+            on_change: self.on_change,
+        };
+        let tags: Vec<(String, String)> = self
+            .options
+            .iter()
+            .filter(|(value, _)| self.selected.contains(value))
+            .cloned()
+            .collect();
+        let display = tags
+            .first()
+            .map(|(_, label)| label.clone())
+            .unwrap_or_default();
+        let rows: Vec<SelectOption> = model
+            .filtered(&state.query)
+            .into_iter()
+            .enumerate()
+            .map(|(index, (value, label))| SelectOption {
+                select: id,
+                index,
+                selected: self.selected.contains(&value),
+                highlighted: state.highlighted == Some(index),
+                value,
+                label,
+                tokens,
+                text: colors.text,
+                highlight,
+                mark: ring,
+            })
+            .collect();
+        let no_results = rows.is_empty();
+
+        let on_dismiss = cx.create_callback(move |world: &mut World| {
+            close_menu(world, id, true);
+        });
+        let on_query = cx.create_callback(
+            move |query: In<String>, mut states: Query<&mut SelectState>| {
+                if let Ok(mut state) = states.get_mut(id) {
+                    state.query = query.0;
+                    state.highlighted = Some(0);
+                }
+            },
+        );
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Select")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Column)
+                        .width(Val::Percent(100.));
+                },
+                self.style.clone(),
+            ))
+            .insert_dyn(
+                move |model| {
+                    (
+                        model,
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            let key = world.resource::<ListenerInput<KeyPressEvent>>().key_code;
+                            if handle_key(world, id, key) {
+                                world
+                                    .resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .stop_propagation();
+                            }
+                        }),
+                    )
+                },
+                model,
+            )
+            .children((
+                Element::<NodeBundle>::for_entity(trigger)
+                    .named("Select::Trigger")
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.display(bevy::ui::Display::Flex)
+                            .flex_direction(bevy::ui::FlexDirection::Row)
+                            .flex_wrap(bevy::ui::FlexWrap::Wrap)
+                            .align_items(bevy::ui::AlignItems::Center)
+                            .width(Val::Percent(100.));
+                    })
+                    .style_dyn(
+                        |(colors, ring, focused, disabled, tokens, opacity), sb| {
+                            let dim = |c: Srgba| {
+                                if disabled {
+                                    c.with_alpha(c.alpha * opacity)
+                                } else {
+                                    c
+                                }
+                            };
+
+                            sb.background_color(dim(colors.bg))
+                                .color(dim(colors.text))
+                                .border(if focused { 2 } else { 1 })
+                                .border_color(dim(if focused { ring } else { colors.border }))
+                                .border_radius(tokens.radius)
+                                .column_gap(tokens.gap)
+                                .row_gap(4)
+                                .font_size(tokens.font_size)
+                                .padding((tokens.padding_x, tokens.padding_y))
+                                .cursor(if disabled {
+                                    CursorIcon::NotAllowed
+                                } else {
+                                    CursorIcon::Pointer
+                                });
+                        },
+                        (
+                            colors,
+                            ring,
+                            focused,
+                            disabled,
+                            tokens,
+                            theme.disabled_opacity(),
+                        ),
+                    )
+                    .insert_if(disabled, || Disabled)
+                    .insert_dyn(
+                        move |(open, disabled, tab_index, display)| {
+                            let mut node = NodeBuilder::new(Role::ComboBox);
+                            node.set_expanded(open);
+                            node.set_value(display);
+                            if disabled {
+                                node.set_disabled();
+                            }
+
+                            (
+                                AccessibilityNode::from(node),
+                                TabIndex(if disabled { -1 } else { tab_index }),
+                                click_to_focus(trigger, move |world: &mut World| {
+                                    if open {
+                                        close_menu(world, id, true);
+                                    } else {
+                                        open_menu(world, id);
+                                    }
+                                }),
+                            )
+                        },
+                        (open, disabled, self.tab_index, display.clone()),
+                    )
+                    .children((
+                        Cond::new(
+                            self.multiple && !tags.is_empty(),
+                            For::each(tags, move |(value, label)| SelectTag {
+                                select: id,
+                                value: value.clone(),
+                                label: label.clone(),
+                                tokens,
+                                bg: highlight,
+                            }),
+                            Cond::new(
+                                !display.is_empty(),
+                                display.clone(),
+                                Element::<NodeBundle>::new()
+                                    .named("Select::Placeholder")
+                                    .style_dyn(
+                                        |color, ss: &mut StyleBuilder| {
+                                            ss.color(color);
+                                        },
+                                        colors.placeholder,
+                                    )
+                                    .children(self.placeholder.clone()),
+                            ),
+                        ),
+                        Element::<NodeBundle>::new().style(|ss: &mut StyleBuilder| {
+                            ss.flex_grow(1.);
+                        }),
+                        // an L shape rotated into a chevron
+                        Element::<NodeBundle>::new()
+                            .named("Select::Chevron")
+                            .style_dyn(
+                                |(size, color), ss: &mut StyleBuilder| {
+                                    ss.width(size * 0.4)
+                                        .height(size * 0.4)
+                                        .border(UiRect {
+                                            right: Val::Px(2.),
+                                            bottom: Val::Px(2.),
+                                            ..Default::default()
+                                        })
+                                        .border_color(color);
+                                },
+                                (tokens.font_size, colors.placeholder),
+                            )
+                            .insert_dyn(
+                                |open| {
+                                    Transform::from_rotation(Quat::from_rotation_z(if open {
+                                        -3. * FRAC_PI_4
+                                    } else {
+                                        FRAC_PI_4
+                                    }))
+                                },
+                                open,
+                            ),
+                    )),
+                // Rendered under the overlay root so no scroll view clips it.
+                Cond::new(
+                    open,
+                    Portal::new(
+                        Element::<NodeBundle>::new()
+                            .named("Select::Menu")
+                            .style_dyn(
+                                |(colors, tokens), ss: &mut StyleBuilder| {
+                                    ss.position(bevy::ui::PositionType::Absolute)
+                                        .display(bevy::ui::Display::Flex)
+                                        .flex_direction(bevy::ui::FlexDirection::Column)
+                                        .row_gap(4)
+                                        .padding(4)
+                                        .border(1)
+                                        .border_color(colors.border)
+                                        .border_radius(tokens.radius)
+                                        .background_color(colors.bg)
+                                        .font_size(tokens.font_size);
+                                },
+                                (colors, tokens),
+                            )
+                            .insert_dyn(
+                                move |on_dismiss| {
+                                    (
+                                        AccessibilityNode::from(NodeBuilder::new(Role::ListBox)),
+                                        Floating {
+                                            anchor: trigger,
+                                            placement: FloatPlacement::default(),
+                                            gap: 4.,
+                                            match_width: true,
+                                        },
+                                        FloatingDismiss {
+                                            anchor: Some(trigger),
+                                            on_dismiss,
+                                        },
+                                        OverlayLayer,
+                                    )
+                                },
+                                on_dismiss,
+                            )
+                            .children((
+                                Cond::new(
+                                    self.searchable,
+                                    Input::new()
+                                        .value(state.query.clone())
+                                        .placeholder("Search...")
+                                        .color(self.color)
+                                        .size(self.size)
+                                        .autofocus(true)
+                                        .on_change(on_query),
+                                    (),
+                                ),
+                                ScrollView::new()
+                                    .style(|ss: &mut StyleBuilder| {
+                                        ss.max_height(MENU_MAX_HEIGHT);
+                                    })
+                                    .scroll_enable_y(true)
+                                    .children(For::each(rows, |row| row.clone())),
+                                Cond::new(
+                                    no_results,
+                                    Element::<NodeBundle>::new()
+                                        .named("Select::Empty")
+                                        .style_dyn(
+                                            |(colors, tokens), ss: &mut StyleBuilder| {
+                                                ss.color(colors.placeholder)
+                                                    .padding((tokens.padding_x, tokens.padding_y));
+                                            },
+                                            (colors, tokens),
+                                        )
+                                        .children("No results"),
+                                    (),
+                                ),
+                            )),
+                    ),
+                    (),
+                ),
+            ))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct SelectOption {
+    select: Entity,
+    index: usize,
+    value: String,
+    label: String,
+    selected: bool,
+    highlighted: bool,
+    tokens: SizeTokens,
+    text: Srgba,
+    highlight: Srgba,
+    mark: Srgba,
+}
+
+impl ViewTemplate for SelectOption {
+    type View = impl View;
+
+    fn create(&self, _cx: &mut bevy_quill::Cx) -> Self::View {
+        let select = self.select;
+        let index = self.index;
+        let value = self.value.clone();
+
+        Element::<NodeBundle>::new()
+            .named("Select::Option")
+            .style_dyn(
+                |(tokens, text, bg), ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Row)
+                        .align_items(bevy::ui::AlignItems::Center)
+                        .column_gap(tokens.gap)
+                        .padding((tokens.padding_x, tokens.padding_y))
+                        .border_radius(tokens.radius)
+                        .color(text)
+                        .background_color(bg)
+                        .cursor(CursorIcon::Pointer);
+                },
+                (
+                    self.tokens,
+                    self.text,
+                    if self.highlighted {
+                        self.highlight
+                    } else {
+                        Srgba::NONE
+                    },
+                ),
+            )
+            .insert_dyn(
+                move |(label, selected)| {
+                    let mut node = NodeBuilder::new(Role::ListBoxOption);
+                    node.set_name(label);
+                    node.set_selected(selected);
+                    let value = value.clone();
+
+                    (
+                        AccessibilityNode::from(node),
+                        On::<Pointer<Over>>::run(move |world: &mut World| {
+                            if let Some(mut state) = world.get_mut::<SelectState>(select) {
+                                if state.highlighted != Some(index) {
+                                    state.highlighted = Some(index);
+                                }
+                            }
+                        }),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                .unwrap();
+                            event.stop_propagation();
+                            choose(world, select, value.clone());
+                        }),
+                    )
+                },
+                (self.label.clone(), self.selected),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.flex_grow(1.);
+                    })
+                    .children(self.label.clone()),
+                Cond::new(
+                    self.selected,
+                    // an L shape rotated into a check mark
+                    Element::<NodeBundle>::new()
+                        .style_dyn(
+                            |(size, color), ss: &mut StyleBuilder| {
+                                ss.width(size * 0.3)
+                                    .height(size * 0.55)
+                                    .margin_bottom(size * 0.1)
+                                    .border(UiRect {
+                                        right: Val::Px(2.),
+                                        bottom: Val::Px(2.),
+                                        ..Default::default()
+                                    })
+                                    .border_color(color);
+                            },
+                            (self.tokens.font_size, self.mark),
+                        )
+                        .insert_dyn(
+                            |_| Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_4)),
+                            (),
+                        ),
+                    (),
+                ),
+            ))
+    }
+}
+
+// A selected value shown in the trigger of a multi-select; clicking the
+// cross removes it.
+#[derive(Clone, PartialEq)]
+struct SelectTag {
+    select: Entity,
+    value: String,
+    label: String,
+    tokens: SizeTokens,
+    bg: Srgba,
+}
+
+impl ViewTemplate for SelectTag {
+    type View = impl View;
+
+    fn create(&self, _cx: &mut bevy_quill::Cx) -> Self::View {
+        let select = self.select;
+        let value = self.value.clone();
+
+        Element::<NodeBundle>::new()
+            .named("Select::Tag")
+            .style_dyn(
+                |(tokens, bg), ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Row)
+                        .align_items(bevy::ui::AlignItems::Center)
+                        .column_gap(4)
+                        .padding((6, 0))
+                        .border_radius(tokens.radius)
+                        .font_size(tokens.font_size * 0.85)
+                        .background_color(bg);
+                },
+                (self.tokens, self.bg),
+            )
+            .children((
+                self.label.clone(),
+                Element::<NodeBundle>::new()
+                    .named("Select::TagRemove")
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.cursor(CursorIcon::Pointer);
+                    })
+                    .insert_dyn(
+                        move |value: String| {
+                            On::<Pointer<Click>>::run(move |world: &mut World| {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                    .unwrap();
+                                event.stop_propagation();
+                                choose(world, select, value.clone());
+                            })
+                        },
+                        value,
+                    )
+                    .children("x"),
+            ))
+    }
+}
+
+fn open_menu(world: &mut World, id: Entity) {
+    let Some(model) = world.get::<SelectModel>(id).cloned() else {
+        return;
+    };
+    if let Some(mut state) = world.get_mut::<SelectState>(id) {
+        state.open = true;
+        state.query.clear();
+        state.highlighted = model
+            .options
+            .iter()
+            .position(|(value, _)| model.selected.contains(value))
+            .or((!model.options.is_empty()).then_some(0));
+    }
+}
+
+fn close_menu(world: &mut World, id: Entity, refocus: bool) {
+    let Some(model) = world.get::<SelectModel>(id).cloned() else {
+        return;
+    };
+    if let Some(mut state) = world.get_mut::<SelectState>(id) {
+        if !state.open {
+            return;
+        }
+        state.open = false;
+        state.query.clear();
+        state.highlighted = None;
+    }
+    if refocus {
+        world.resource_mut::<Focus>().0 = Some(model.trigger);
+    }
+}
+
+// Selects `value`, or toggles it in a multi-select.
+fn choose(world: &mut World, id: Entity, value: String) {
+    let Some(model) = world.get::<SelectModel>(id).cloned() else {
+        return;
+    };
+    let selected = if model.multiple {
+        let mut selected = model.selected.clone();
+        match selected.iter().position(|v| *v == value) {
+            Some(index) => {
+                selected.remove(index);
+            }
+            None => selected.push(value),
+        }
+        selected
+    } else {
+        close_menu(world, id, true);
+        vec![value]
+    };
+    if let Some(on_change) = model.on_change {
+        world.run_callback(on_change, selected);
+    }
+}
+
+// Returns whether the key was used.
+fn handle_key(world: &mut World, id: Entity, key: KeyCode) -> bool {
+    let (Some(model), Some(state)) = (
+        world.get::<SelectModel>(id).cloned(),
+        world.get::<SelectState>(id).cloned(),
+    ) else {
+        return false;
+    };
+    if world.is_disabled(model.trigger) {
+        return false;
+    }
+
+    if !state.open {
+        return match key {
+            KeyCode::ArrowDown | KeyCode::ArrowUp | KeyCode::Enter | KeyCode::Space => {
+                open_menu(world, id);
+                true
+            }
+            _ => false,
+        };
+    }
+
+    let options = model.filtered(&state.query);
+    let last = options.len().saturating_sub(1);
+    let highlight = |world: &mut World, index: Option<usize>| {
+        if let Some(mut state) = world.get_mut::<SelectState>(id) {
+            state.highlighted = index;
+        }
+    };
+    match key {
+        KeyCode::ArrowDown if !options.is_empty() => {
+            let next = state.highlighted.map_or(0, |i| (i + 1) % options.len());
+            highlight(world, Some(next));
+            true
+        }
+        KeyCode::ArrowUp if !options.is_empty() => {
+            let next = state
+                .highlighted
+                .map_or(last, |i| (i + last) % options.len());
+            highlight(world, Some(next));
+            true
+        }
+        KeyCode::Home if !options.is_empty() => {
+            highlight(world, Some(0));
+            true
+        }
+        KeyCode::End if !options.is_empty() => {
+            highlight(world, Some(last));
+            true
+        }
+        KeyCode::Enter => {
+            if let Some((value, _)) = state.highlighted.and_then(|i| options.get(i)) {
+                choose(world, id, value.clone());
+            }
+            true
+        }
+        KeyCode::Space if !model.searchable => {
+            if let Some((value, _)) = state.highlighted.and_then(|i| options.get(i)) {
+                choose(world, id, value.clone());
+            }
+            true
+        }
+        KeyCode::Escape => {
+            close_menu(world, id, true);
+            true
+        }
+        // Let focus move on.
+        KeyCode::Tab => {
+            close_menu(world, id, false);
+            false
+        }
+        _ => false,
+    }
+}
+
+// Jumps to the first option whose label starts with the recently typed text.
+// A closed single select changes its value directly, like a native select.
+fn typeahead(world: &mut World, id: Entity, text: &str) {
+    let now = world.resource::<Time>().elapsed_seconds();
+    let Some(model) = world.get::<SelectModel>(id).cloned() else {
+        return;
+    };
+    if world.is_disabled(model.trigger) {
+        return;
+    }
+    let Some(mut state) = world.get_mut::<SelectState>(id) else {
+        return;
+    };
+    // An open searchable select types into its search field instead.
+    if state.open && model.searchable {
+        return;
+    }
+    if now - state.typed_at > TYPEAHEAD_TIMEOUT {
+        state.typeahead.clear();
+    }
+    state.typed_at = now;
+    state.typeahead.push_str(&text.to_lowercase());
+
+    let prefix = state.typeahead.clone();
+    let Some(index) = model
+        .options
+        .iter()
+        .position(|(_, label)| label.to_lowercase().starts_with(&prefix))
+    else {
+        return;
+    };
+    if state.open {
+        state.highlighted = Some(index);
+        return;
+    }
+    if !model.multiple {
+        choose(world, id, model.options[index].0.clone());
+    }
+}
+
+// Feeds typed characters to the focused select. Uses the logical key, so
+// type-ahead follows the keyboard layout rather than physical key positions.
+pub(crate) fn typeahead_selects(
+    mut commands: Commands,
+    mut events: EventReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<Focus>,
+    selects: Query<(Entity, &SelectModel)>,
+) {
+    let Some(id) = focus.0.and_then(|focused| {
+        selects
+            .iter()
+            .find(|(_, model)| model.trigger == focused)
+            .map(|(id, _)| id)
+    }) else {
+        events.clear();
+        return;
+    };
+    // Shortcuts are not type-ahead.
+    if keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]) {
+        events.clear();
+        return;
+    }
+
+    let mut text = String::new();
+    for event in events.read() {
+        if let (ButtonState::Pressed, Key::Character(c)) = (event.state, &event.logical_key) {
+            text.push_str(c);
+        }
+    }
+    if !text.is_empty() {
+        commands.add(move |world: &mut World| typeahead(world, id, &text));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::{App, Update};
+
+    use super::*;
+    use crate::ui::testing::{headless_app, named, settle, spawn_view};
+    use crate::ui::Modal;
+
+    #[test]
+    fn typeahead_follows_the_keyboard_layout() {
+        let mut app = App::new();
+        app.add_event::<KeyboardInput>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Focus>()
+            .init_resource::<Time>()
+            .add_systems(Update, typeahead_selects);
+        let trigger = app.world_mut().spawn_empty().id();
+        let select = app
+            .world_mut()
+            .spawn((
+                SelectModel {
+                    trigger,
+                    options: vec![
+                        ("banana".to_string(), "Banana".to_string()),
+                        ("apple".to_string(), "Apple".to_string()),
+                    ],
+                    selected: Vec::new(),
+                    multiple: false,
+                    searchable: false,
+                    on_change: None,
+                },
+                SelectState {
+                    open: true,
+                    ..Default::default()
+                },
+            ))
+            .id();
+        app.world_mut().resource_mut::<Focus>().0 = Some(trigger);
+
+        // On AZERTY, the key where QWERTY has "Q" types "a".
+        app.world_mut().send_event(KeyboardInput {
+            key_code: KeyCode::KeyQ,
+            logical_key: Key::Character("a".into()),
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+
+        let state = app.world().get::<SelectState>(select).unwrap();
+        assert_eq!(state.highlighted, Some(1));
+    }
+
+    #[test]
+    fn search_field_keeps_focus_inside_a_modal() {
+        let mut app = headless_app();
+        spawn_view(
+            &mut app,
+            Modal::new().open(true).children(
+                Select::new()
+                    .searchable(true)
+                    .option("apple", "Apple")
+                    .option("banana", "Banana"),
+            ),
+        );
+        let select = named(&mut app, "Select")[0];
+        let trigger = named(&mut app, "Select::Trigger")[0];
+        assert_eq!(app.world().resource::<Focus>().0, Some(trigger));
+
+        open_menu(app.world_mut(), select);
+        settle(&mut app);
+
+        // The menu lives under the overlay root, beside the modal, but counts
+        // as part of it for the focus trap.
+        let search = named(&mut app, "Input")[0];
+        assert_eq!(app.world().resource::<Focus>().0, Some(search));
+    }
+}
@@ -0,0 +1,59 @@
+use super::{ButtonSize, QuillUiTheme};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SizeTokens {
+    pub font_size: f32,
+    pub padding_x: f32,
+    pub padding_y: f32,
+    pub gap: f32,
+    pub icon_size: f32,
+    pub radius: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SizeScale {
+    pub xs: SizeTokens,
+    pub sm: SizeTokens,
+    pub md: SizeTokens,
+    pub lg: SizeTokens,
+    pub xl: SizeTokens,
+}
+
+impl SizeScale {
+    pub fn get(&self, size: ButtonSize) -> SizeTokens {
+        match size {
+            ButtonSize::XS => self.xs,
+            ButtonSize::SM => self.sm,
+            ButtonSize::MD => self.md,
+            ButtonSize::LG => self.lg,
+            ButtonSize::XL => self.xl,
+        }
+    }
+}
+
+impl Default for SizeScale {
+    fn default() -> Self {
+        let tokens = |font_size, padding_x, padding_y, gap, icon_size, radius| SizeTokens {
+            font_size,
+            padding_x,
+            padding_y,
+            gap,
+            icon_size,
+            radius,
+        };
+
+        Self {
+            xs: tokens(12., 8., 4., 4., 14., 4.),
+            sm: tokens(14., 10., 6., 6., 16., 6.),
+            md: tokens(16., 12., 8., 8., 20., 6.),
+            lg: tokens(18., 14., 10., 10., 22., 8.),
+            xl: tokens(20., 16., 12., 12., 24., 8.),
+        }
+    }
+}
+
+impl QuillUiTheme {
+    pub fn size(&self, size: ButtonSize) -> SizeTokens {
+        self.sizes.get(size)
+    }
+}
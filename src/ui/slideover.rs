@@ -0,0 +1,189 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::NodeBundle,
+    ui::{PositionType, Val},
+};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderFont, StyleBuilderLayout, StyleHandle,
+    StyleTuple,
+};
+use bevy_quill::{Callback, Cx, Element, IntoViewChild, View, ViewChild, ViewTemplate};
+
+use super::{Overlay, UseOverlayTransition, UseTheme};
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum SlideoverSide {
+    Left,
+    #[default]
+    Right,
+    Top,
+    Bottom,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Slideover {
+    pub open: bool,
+    pub side: SlideoverSide,
+    // Width for left/right panels, height for top/bottom ones.
+    pub size: f32,
+    // A non-modal slideover has no backdrop and leaves the rest of the
+    // screen interactive.
+    pub modal: bool,
+    pub children: ViewChild,
+    pub style: StyleHandle,
+    pub close_on_escape: bool,
+    pub close_on_backdrop: bool,
+
+    // Requests closing; the owner is expected to clear `open`.
+    pub on_close: Option<Callback<()>>,
+}
+
+impl Default for Slideover {
+    fn default() -> Self {
+        Self {
+            open: false,
+            side: SlideoverSide::default(),
+            size: 400.,
+            modal: true,
+            children: ViewChild::default(),
+            style: StyleHandle::default(),
+            close_on_escape: true,
+            close_on_backdrop: true,
+            on_close: None,
+        }
+    }
+}
+
+impl Slideover {
+    pub fn new() -> Slideover {
+        Self::default()
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn side(mut self, side: SlideoverSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    pub fn close_on_backdrop(mut self, close_on_backdrop: bool) -> Self {
+        self.close_on_backdrop = close_on_backdrop;
+        self
+    }
+
+    pub fn on_close(mut self, callback: Callback<()>) -> Self {
+        self.on_close = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Slideover {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let transition = cx.use_overlay_transition(id, self.open);
+        let progress = transition.eased();
+        let theme = cx.use_theme();
+
+        // Like `Modal`, the slideover takes no space; its overlay covers the window.
+        Element::<NodeBundle>::for_entity(id)
+            .named("Slideover")
+            .style(|ss: &mut StyleBuilder| {
+                ss.position(PositionType::Absolute);
+            })
+            .children(
+                Overlay::new("Slideover::Overlay")
+                    .transition(transition)
+                    .modal(self.modal)
+                    .close_on_escape(self.close_on_escape)
+                    .close_on_backdrop(self.close_on_backdrop)
+                    .on_close(self.on_close)
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .named("Slideover::Panel")
+                            .style((
+                                |ss: &mut StyleBuilder| {
+                                    ss.display(bevy::ui::Display::Flex)
+                                        .flex_direction(bevy::ui::FlexDirection::Column)
+                                        .gap(12)
+                                        .padding(24);
+                                },
+                                self.style.clone(),
+                            ))
+                            .style_dyn(
+                                |(side, size, progress, surface, text), ss: &mut StyleBuilder| {
+                                    // Slides in from just past the edge.
+                                    let offset = Val::Px(-size * (1. - progress));
+                                    ss.position(PositionType::Absolute)
+                                        .background_color(surface)
+                                        .color(text);
+                                    match side {
+                                        SlideoverSide::Left | SlideoverSide::Right => {
+                                            ss.top(0).width(size).height(Val::Percent(100.));
+                                        }
+                                        SlideoverSide::Top | SlideoverSide::Bottom => {
+                                            ss.left(0).width(Val::Percent(100.)).height(size);
+                                        }
+                                    }
+                                    match side {
+                                        SlideoverSide::Left => ss.left(offset),
+                                        SlideoverSide::Right => ss.right(offset),
+                                        SlideoverSide::Top => ss.top(offset),
+                                        SlideoverSide::Bottom => ss.bottom(offset),
+                                    };
+                                },
+                                (
+                                    self.side,
+                                    self.size,
+                                    progress,
+                                    theme.surface(),
+                                    theme.text(),
+                                ),
+                            )
+                            .insert_dyn(
+                                |modal| {
+                                    let mut node = NodeBuilder::new(Role::Dialog);
+                                    if modal {
+                                        node.set_modal();
+                                    }
+                                    AccessibilityNode::from(node)
+                                },
+                                self.modal,
+                            )
+                            .children(self.children.clone()),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,798 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    color::{Alpha, Color, Srgba},
+    prelude::{Children, Component, Entity, GlobalTransform, KeyCode, NodeBundle, World},
+    ui::{Node, PositionType, Val},
+    window::CursorIcon,
+};
+use bevy_mod_picking::prelude::{Down, Drag, DragEnd, ListenerInput, On, Pointer};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderLayout, StyleBuilderOutline, StyleHandle, StyleTuple,
+};
+use bevy_quill::{Callback, Cx, Element, For, RunCallback, View, ViewTemplate};
+use bevy_quill_obsidian::{
+    controls::{Disabled, IsDisabled},
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::UseIsFocus,
+};
+
+use super::{button_ring_color, ButtonColor, ButtonSize, QuillUiTheme, ThemeMode, UseTheme};
+
+// PageUp and PageDown move by this fraction of the range.
+const PAGE_FRACTION: f32 = 0.1;
+
+// Tick marks are skipped when there would be more than this many.
+const MAX_TICKS: usize = 100;
+
+#[derive(Clone, PartialEq)]
+pub struct Slider {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    // Spacing of the tick marks, in value units.
+    pub ticks: Option<f32>,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+    pub disabled: bool,
+    pub tab_index: i32,
+
+    pub on_change: Option<Callback<f32>>,
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self {
+            value: 0.,
+            min: 0.,
+            max: 100.,
+            step: 1.,
+            ticks: None,
+            color: ButtonColor::default(),
+            size: ButtonSize::default(),
+            style: StyleHandle::default(),
+            disabled: false,
+            tab_index: 0,
+            on_change: None,
+        }
+    }
+}
+
+impl Slider {
+    pub fn new() -> Slider {
+        Self::default()
+    }
+
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn ticks(mut self, spacing: f32) -> Self {
+        self.ticks = Some(spacing);
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<f32>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Slider {
+    type View = impl View;
+
+    fn create(&self, _cx: &mut Cx) -> Self::View {
+        SliderTrack {
+            values: vec![self.value],
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            ticks: self.ticks,
+            color: self.color,
+            size: self.size,
+            style: self.style.clone(),
+            disabled: self.disabled,
+            tab_index: self.tab_index,
+            on_change: SliderCallback::Single(self.on_change),
+        }
+    }
+}
+
+// A slider with two thumbs selecting the range between them.
+#[derive(Clone, PartialEq)]
+pub struct RangeSlider {
+    pub range: (f32, f32),
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    // Spacing of the tick marks, in value units.
+    pub ticks: Option<f32>,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+    pub disabled: bool,
+    pub tab_index: i32,
+
+    pub on_change: Option<Callback<(f32, f32)>>,
+}
+
+impl Default for RangeSlider {
+    fn default() -> Self {
+        Self {
+            range: (0., 100.),
+            min: 0.,
+            max: 100.,
+            step: 1.,
+            ticks: None,
+            color: ButtonColor::default(),
+            size: ButtonSize::default(),
+            style: StyleHandle::default(),
+            disabled: false,
+            tab_index: 0,
+            on_change: None,
+        }
+    }
+}
+
+impl RangeSlider {
+    pub fn new() -> RangeSlider {
+        Self::default()
+    }
+
+    pub fn range(mut self, low: f32, high: f32) -> Self {
+        self.range = (low, high);
+        self
+    }
+
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn ticks(mut self, spacing: f32) -> Self {
+        self.ticks = Some(spacing);
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<(f32, f32)>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for RangeSlider {
+    type View = impl View;
+
+    fn create(&self, _cx: &mut Cx) -> Self::View {
+        SliderTrack {
+            values: vec![self.range.0, self.range.1],
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            ticks: self.ticks,
+            color: self.color,
+            size: self.size,
+            style: self.style.clone(),
+            disabled: self.disabled,
+            tab_index: self.tab_index,
+            on_change: SliderCallback::Range(self.on_change),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SliderCallback {
+    Single(Option<Callback<f32>>),
+    Range(Option<Callback<(f32, f32)>>),
+}
+
+// Props of a slider, kept on its entity for the event handlers.
+#[derive(Component, Clone, PartialEq)]
+struct SliderModel {
+    values: Vec<f32>,
+    min: f32,
+    max: f32,
+    step: f32,
+    on_change: SliderCallback,
+}
+
+impl SliderModel {
+    fn snap(&self, value: f32) -> f32 {
+        let value = if self.step > 0. {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        } else {
+            value
+        };
+        value.clamp(self.min, self.max)
+    }
+
+    fn fraction(&self, value: f32) -> f32 {
+        if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0., 1.)
+        } else {
+            0.
+        }
+    }
+}
+
+// Index of a thumb within its slider.
+#[derive(Component, Clone, Copy, PartialEq)]
+struct SliderThumb(usize);
+
+// The thumb being dragged. A press that could grab either of two thumbs
+// waits for the drag direction: `low` when moving down, `high` when moving up.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+enum SliderDrag {
+    Thumb(usize),
+    Undecided { low: usize, high: usize, from: f32 },
+}
+
+// The thumb a press at `value` grabs: the nearest one. When two are equally
+// near, a press outside them picks the one on its side and a press between
+// (or right on) them is left to the drag.
+fn grab_thumb(values: &[f32], value: f32) -> Option<SliderDrag> {
+    let nearest = values
+        .iter()
+        .map(|v| (v - value).abs())
+        .min_by(f32::total_cmp)?;
+    let tied: Vec<usize> = (0..values.len())
+        .filter(|i| (values[*i] - value).abs() == nearest)
+        .collect();
+    match tied.as_slice() {
+        [index] => Some(SliderDrag::Thumb(*index)),
+        [low, ..] if value < values[*low] => Some(SliderDrag::Thumb(*low)),
+        [.., high] if value > values[*high] => Some(SliderDrag::Thumb(*high)),
+        [low, .., high] => Some(SliderDrag::Undecided {
+            low: *low,
+            high: *high,
+            from: value,
+        }),
+        [] => None,
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct SliderTrack {
+    values: Vec<f32>,
+    min: f32,
+    max: f32,
+    step: f32,
+    ticks: Option<f32>,
+    color: ButtonColor,
+    size: ButtonSize,
+    style: StyleHandle,
+    disabled: bool,
+    tab_index: i32,
+    on_change: SliderCallback,
+}
+
+impl ViewTemplate for SliderTrack {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let disabled = self.disabled;
+        let theme = cx.use_theme();
+        let tokens = theme.size(self.size);
+        let (mut rail, mut fill, mut thumb) = slider_colors(self.color, theme.clone());
+        if disabled {
+            let opacity = theme.disabled_opacity();
+            rail = rail.with_alpha(rail.alpha * opacity);
+            fill = fill.with_alpha(fill.alpha * opacity);
+            thumb = thumb.with_alpha(thumb.alpha * opacity);
+        }
+        let thumb_size = tokens.font_size;
+        let rail_height = (tokens.font_size * 0.3).round();
+
+        let model = SliderModel {
+            values: self.values.clone(),
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            on_change: self.on_change,
+        };
+        let fractions: Vec<f32> = self.values.iter().map(|v| model.fraction(*v)).collect();
+        let (fill_start, fill_end) = match fractions.as_slice() {
+            [value] => (0., *value),
+            [low, high] => (*low, *high),
+            _ => (0., 0.),
+        };
+        let ticks: Vec<f32> = match self.ticks {
+            Some(spacing)
+                if spacing > 0. && (self.max - self.min) / spacing <= MAX_TICKS as f32 =>
+            {
+                let count = ((self.max - self.min) / spacing).floor() as usize;
+                (0..=count)
+                    .map(|i| model.fraction(self.min + i as f32 * spacing))
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        let thumbs: Vec<SliderThumbView> = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| SliderThumbView {
+                index,
+                value: *value,
+                fraction: fractions[index],
+                min: self.min,
+                max: self.max,
+                step: self.step,
+                size: thumb_size,
+                bg: thumb,
+                border: fill,
+                ring: button_ring_color(self.color, theme.clone()),
+                disabled,
+                tab_index: self.tab_index,
+            })
+            .collect();
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Slider")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .align_items(bevy::ui::AlignItems::Center)
+                        .width(Val::Percent(100.));
+                },
+                self.style.clone(),
+            ))
+            .style_dyn(
+                |(size, disabled), ss: &mut StyleBuilder| {
+                    ss.height(size).cursor(if disabled {
+                        CursorIcon::NotAllowed
+                    } else {
+                        CursorIcon::Pointer
+                    });
+                },
+                (thumb_size, disabled),
+            )
+            .insert_if(disabled, || Disabled)
+            .insert_dyn(
+                move |model| {
+                    (
+                        model,
+                        On::<Pointer<Down>>::run(move |world: &mut World| {
+                            if world.is_disabled(id) {
+                                return;
+                            }
+                            let x = world
+                                .resource::<ListenerInput<Pointer<Down>>>()
+                                .pointer_location
+                                .position
+                                .x;
+                            let (Some(value), Some(model)) = (
+                                value_at(world, id, x),
+                                world.get::<SliderModel>(id).cloned(),
+                            ) else {
+                                return;
+                            };
+                            let Some(drag) = grab_thumb(&model.values, value) else {
+                                return;
+                            };
+                            world.entity_mut(id).insert(drag);
+                            if let SliderDrag::Thumb(index) = drag {
+                                if let Some(thumb) = thumb_entity(world, id, index) {
+                                    world.resource_mut::<Focus>().0 = Some(thumb);
+                                }
+                                set_value(world, id, index, value);
+                            }
+                        }),
+                        On::<Pointer<Drag>>::run(move |world: &mut World| {
+                            if world.is_disabled(id) {
+                                return;
+                            }
+                            let x = world
+                                .resource::<ListenerInput<Pointer<Drag>>>()
+                                .pointer_location
+                                .position
+                                .x;
+                            let Some(value) = value_at(world, id, x) else {
+                                return;
+                            };
+                            let index = match world.get::<SliderDrag>(id).copied() {
+                                Some(SliderDrag::Thumb(index)) => index,
+                                Some(SliderDrag::Undecided { low, high, from }) => {
+                                    if value == from {
+                                        return;
+                                    }
+                                    let index = if value < from { low } else { high };
+                                    world.entity_mut(id).insert(SliderDrag::Thumb(index));
+                                    if let Some(thumb) = thumb_entity(world, id, index) {
+                                        world.resource_mut::<Focus>().0 = Some(thumb);
+                                    }
+                                    index
+                                }
+                                None => return,
+                            };
+                            set_value(world, id, index, value);
+                        }),
+                        On::<Pointer<DragEnd>>::run(move |world: &mut World| {
+                            world.entity_mut(id).remove::<SliderDrag>();
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if world.is_disabled(id) {
+                                return;
+                            }
+                            let event = world.resource::<ListenerInput<KeyPressEvent>>();
+                            let (target, key) = (event.target, event.key_code);
+                            let (Some(model), Some(SliderThumb(index))) = (
+                                world.get::<SliderModel>(id).cloned(),
+                                world.get::<SliderThumb>(target).copied(),
+                            ) else {
+                                return;
+                            };
+                            let step = if model.step > 0. { model.step } else { 1. };
+                            let page = ((model.max - model.min) * PAGE_FRACTION).max(step);
+                            let value = model.values[index];
+                            let value = match key {
+                                KeyCode::ArrowLeft | KeyCode::ArrowDown => value - step,
+                                KeyCode::ArrowRight | KeyCode::ArrowUp => value + step,
+                                KeyCode::PageDown => value - page,
+                                KeyCode::PageUp => value + page,
+                                KeyCode::Home => model.min,
+                                KeyCode::End => model.max,
+                                _ => return,
+                            };
+                            world
+                                .resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .stop_propagation();
+                            set_value(world, id, index, value);
+                        }),
+                    )
+                },
+                model,
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("Slider::Rail")
+                    .style_dyn(
+                        |(height, color), ss: &mut StyleBuilder| {
+                            ss.width(Val::Percent(100.))
+                                .height(height)
+                                .border_radius(height / 2.)
+                                .background_color(color);
+                        },
+                        (rail_height, rail),
+                    ),
+                Element::<NodeBundle>::new()
+                    .named("Slider::Fill")
+                    .style_dyn(
+                        |(height, color, start, end), ss: &mut StyleBuilder| {
+                            ss.position(PositionType::Absolute)
+                                .left(Val::Percent(start * 100.))
+                                .width(Val::Percent((end - start) * 100.))
+                                .height(height)
+                                .border_radius(height / 2.)
+                                .background_color(color);
+                        },
+                        (rail_height, fill, fill_start, fill_end),
+                    ),
+                For::each(ticks, move |fraction| {
+                    Element::<NodeBundle>::new()
+                        .named("Slider::Tick")
+                        .style_dyn(
+                            |(fraction, size, color), ss: &mut StyleBuilder| {
+                                ss.position(PositionType::Absolute)
+                                    .left(Val::Percent(fraction * 100.))
+                                    .top(size)
+                                    .margin_left(-1)
+                                    .width(2)
+                                    .height(size * 0.3)
+                                    .background_color(color);
+                            },
+                            (*fraction, thumb_size, rail),
+                        )
+                }),
+                For::each(thumbs, |thumb| thumb.clone()),
+            ))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct SliderThumbView {
+    index: usize,
+    value: f32,
+    fraction: f32,
+    min: f32,
+    max: f32,
+    step: f32,
+    size: f32,
+    bg: Srgba,
+    border: Srgba,
+    ring: Srgba,
+    disabled: bool,
+    tab_index: i32,
+}
+
+impl ViewTemplate for SliderThumbView {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let focused = cx.is_focus_visible(id);
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Slider::Thumb")
+            .style_dyn(
+                |(fraction, size, bg, border, ring, focused), ss: &mut StyleBuilder| {
+                    ss.position(PositionType::Absolute)
+                        .left(Val::Percent(fraction * 100.))
+                        .margin_left(-size / 2.)
+                        .width(size)
+                        .height(size)
+                        .border(2)
+                        .border_radius(size / 2.)
+                        .border_color(border)
+                        .background_color(bg);
+                    if focused {
+                        ss.outline_color(ring).outline_width(2).outline_offset(2);
+                    } else {
+                        ss.outline_color(Color::NONE);
+                    }
+                },
+                (
+                    self.fraction,
+                    self.size,
+                    self.bg,
+                    self.border,
+                    self.ring,
+                    focused,
+                ),
+            )
+            .insert_dyn(
+                |(index, value, min, max, step, disabled, tab_index)| {
+                    let mut node = NodeBuilder::new(Role::Slider);
+                    node.set_numeric_value(value as f64);
+                    node.set_min_numeric_value(min as f64);
+                    node.set_max_numeric_value(max as f64);
+                    node.set_numeric_value_step(step as f64);
+                    if disabled {
+                        node.set_disabled();
+                    }
+                    (
+                        AccessibilityNode::from(node),
+                        SliderThumb(index),
+                        TabIndex(if disabled { -1 } else { tab_index }),
+                    )
+                },
+                (
+                    self.index,
+                    self.value,
+                    self.min,
+                    self.max,
+                    self.step,
+                    self.disabled,
+                    self.tab_index,
+                ),
+            )
+    }
+}
+
+// Rail: bg-gray-200 dark:bg-gray-700. Fill and thumb ring: {color}-500 dark:{color}-400.
+// Thumb: bg-white dark:bg-gray-900.
+fn slider_colors(color: ButtonColor, theme: QuillUiTheme) -> (Srgba, Srgba, Srgba) {
+    let dark = theme.mode == ThemeMode::DARK;
+    let rail = if dark {
+        theme.colors.gray[7]
+    } else {
+        theme.colors.gray[2]
+    };
+    let thumb = if dark {
+        theme.colors.gray[9]
+    } else {
+        theme.colors.white
+    };
+    (rail, button_ring_color(color, theme), thumb)
+}
+
+// Converts a window x coordinate to a snapped value on the slider.
+fn value_at(world: &World, id: Entity, x: f32) -> Option<f32> {
+    let model = world.get::<SliderModel>(id)?;
+    let node = world.get::<Node>(id)?;
+    let transform = world.get::<GlobalTransform>(id)?;
+    let width = node.size().x;
+    if width <= 0. {
+        return None;
+    }
+    let left = transform.translation().x - width / 2.;
+    let fraction = ((x - left) / width).clamp(0., 1.);
+    Some(model.snap(model.min + fraction * (model.max - model.min)))
+}
+
+fn thumb_entity(world: &World, id: Entity, index: usize) -> Option<Entity> {
+    world.get::<Children>(id).and_then(|children| {
+        children
+            .iter()
+            .copied()
+            .find(|child| world.get::<SliderThumb>(*child) == Some(&SliderThumb(index)))
+    })
+}
+
+// Moves one thumb, keeping range thumbs in order, and reports the change.
+fn set_value(world: &mut World, id: Entity, index: usize, value: f32) {
+    let Some(model) = world.get::<SliderModel>(id).cloned() else {
+        return;
+    };
+    let mut value = model.snap(value);
+    if index > 0 {
+        value = value.max(model.values[index - 1]);
+    }
+    if index + 1 < model.values.len() {
+        value = value.min(model.values[index + 1]);
+    }
+    if value == model.values[index] {
+        return;
+    }
+    match model.on_change {
+        SliderCallback::Single(Some(on_change)) => world.run_callback(on_change, value),
+        SliderCallback::Range(Some(on_change)) => {
+            let (low, high) = if index == 0 {
+                (value, model.values[1])
+            } else {
+                (model.values[0], value)
+            };
+            world.run_callback(on_change, (low, high));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        input::keyboard::Key,
+        prelude::{App, In, ResMut, Resource},
+    };
+
+    use super::*;
+    use crate::ui::testing::{headless_app, named, spawn_view, tap_key};
+
+    #[test]
+    fn grabs_the_nearest_thumb() {
+        assert_eq!(grab_thumb(&[20., 80.], 30.), Some(SliderDrag::Thumb(0)));
+        assert_eq!(grab_thumb(&[20., 80.], 70.), Some(SliderDrag::Thumb(1)));
+        assert_eq!(grab_thumb(&[50.], 0.), Some(SliderDrag::Thumb(0)));
+    }
+
+    #[test]
+    fn stacked_thumbs_follow_the_pointer() {
+        // Both thumbs at the top: a press below can only mean the low one.
+        assert_eq!(grab_thumb(&[100., 100.], 90.), Some(SliderDrag::Thumb(0)));
+        assert_eq!(grab_thumb(&[0., 0.], 10.), Some(SliderDrag::Thumb(1)));
+        assert_eq!(
+            grab_thumb(&[40., 40.], 40.),
+            Some(SliderDrag::Undecided {
+                low: 0,
+                high: 1,
+                from: 40.
+            })
+        );
+    }
+
+    #[derive(Resource, Clone, Copy, Debug, PartialEq)]
+    struct Range(f32, f32);
+
+    #[derive(Clone, PartialEq)]
+    struct RangeView;
+
+    impl ViewTemplate for RangeView {
+        type View = impl View;
+
+        fn create(&self, cx: &mut Cx) -> Self::View {
+            let range = *cx.use_resource::<Range>();
+            let on_change =
+                cx.create_callback(|value: In<(f32, f32)>, mut range: ResMut<Range>| {
+                    *range = Range(value.0 .0, value.0 .1);
+                });
+
+            RangeSlider::new()
+                .range(range.0, range.1)
+                .on_change(on_change)
+        }
+    }
+
+    #[test]
+    fn range_thumbs_move_with_keys_and_never_cross() {
+        let mut app = headless_app();
+        app.insert_resource(Range(40., 40.));
+        spawn_view(&mut app, RangeView);
+        let thumbs = named(&mut app, "Slider::Thumb");
+        assert_eq!(thumbs.len(), 2);
+
+        let value = |app: &App| *app.world().resource::<Range>();
+
+        app.world_mut().resource_mut::<Focus>().0 = Some(thumbs[0]);
+        tap_key(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+        assert_eq!(value(&app), Range(40., 40.));
+        tap_key(&mut app, KeyCode::ArrowLeft, Key::ArrowLeft);
+        assert_eq!(value(&app), Range(39., 40.));
+
+        app.world_mut().resource_mut::<Focus>().0 = Some(thumbs[1]);
+        tap_key(&mut app, KeyCode::End, Key::End);
+        assert_eq!(value(&app), Range(39., 100.));
+    }
+}
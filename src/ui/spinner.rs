@@ -0,0 +1,92 @@
+use std::f32::consts::TAU;
+
+use bevy::{
+    color::{Alpha, Srgba},
+    prelude::{Component, NodeBundle, Query, Res, Transform, With},
+    time::Time,
+    ui::PositionType,
+};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderLayout,
+};
+use bevy_quill::{Element, View, ViewTemplate};
+
+// One full turn per second.
+const SPINNER_SPEED: f32 = TAU;
+
+#[derive(Component, Default, Clone, Copy)]
+pub struct SpinnerRotation;
+
+#[derive(Clone, PartialEq)]
+pub struct Spinner {
+    pub size: f32,
+    pub color: Srgba,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self {
+            size: 16.0,
+            color: Srgba::WHITE,
+        }
+    }
+}
+
+impl Spinner {
+    pub fn new() -> Spinner {
+        Self::default()
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn color(mut self, color: Srgba) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl ViewTemplate for Spinner {
+    type View = impl View;
+
+    fn create(&self, _cx: &mut bevy_quill::Cx) -> Self::View {
+        Element::<NodeBundle>::new()
+            .named("Spinner")
+            .style_dyn(
+                |(size, color), ss: &mut StyleBuilder| {
+                    ss.width(size)
+                        .height(size)
+                        .flex_shrink(0.)
+                        .border(2)
+                        .border_radius(size / 2.)
+                        .border_color(color.with_alpha(color.alpha * 0.25));
+                },
+                (self.size, self.color),
+            )
+            .insert_dyn(|_| SpinnerRotation, ())
+            .children(Element::<NodeBundle>::new().style_dyn(
+                |(size, color), ss: &mut StyleBuilder| {
+                    ss.position(PositionType::Absolute)
+                        .top(-2.)
+                        .left(size / 2. - 4.)
+                        .width(4)
+                        .height(4)
+                        .border_radius(2.)
+                        .background_color(color);
+                },
+                (self.size, self.color),
+            ))
+    }
+}
+
+pub(crate) fn rotate_spinners(
+    time: Res<Time>,
+    mut query: Query<&mut Transform, With<SpinnerRotation>>,
+) {
+    for mut transform in query.iter_mut() {
+        transform.rotate_z(-SPINNER_SPEED * time.delta_seconds());
+    }
+}
@@ -0,0 +1,378 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role, Toggled},
+        AccessibilityNode,
+    },
+    color::{Alpha, Color, Srgba},
+    prelude::{Component, KeyCode, NodeBundle, Query, Res, World},
+    time::Time,
+    ui::{PositionType, Style, Val},
+    window::CursorIcon,
+};
+use bevy_mod_picking::prelude::{ListenerInput, On};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderRadius, StyleBuilderFont,
+    StyleBuilderLayout, StyleBuilderOutline, StyleHandle, StyleTuple,
+};
+use bevy_quill::{
+    Callback, Cond, Element, IntoViewChild, RunCallback, View, ViewChild, ViewTemplate,
+};
+use bevy_quill_obsidian::{
+    controls::{Disabled, IsDisabled},
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+};
+
+use super::{
+    button_ring_color, click_to_focus, control_fill, ButtonColor, ButtonSize, QuillUiTheme,
+    ThemeMode, UseTheme,
+};
+
+// Ease-out rate of the thumb; it settles in roughly 150ms.
+const THUMB_SPEED: f32 = 20.0;
+
+// Gap between the track edge and the thumb.
+const THUMB_INSET: f32 = 2.0;
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum SwitchLabelPlacement {
+    Start,
+    #[default]
+    End,
+}
+
+// Left offset the thumb is animating towards.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct SwitchThumb {
+    pub target: f32,
+}
+
+#[derive(Default, Clone, PartialEq)]
+pub struct Switch {
+    pub label: ViewChild,
+    pub label_placement: SwitchLabelPlacement,
+    pub checked: bool,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+    pub disabled: bool,
+    pub tab_index: i32,
+
+    pub on_change: Option<Callback<bool>>,
+}
+
+impl Switch {
+    pub fn new() -> Switch {
+        Self::default()
+    }
+
+    pub fn label(mut self, label: impl IntoViewChild) -> Self {
+        self.label = label.into_view_child();
+        self
+    }
+
+    pub fn label_placement(mut self, placement: SwitchLabelPlacement) -> Self {
+        self.label_placement = placement;
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<bool>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Switch {
+    type View = impl View;
+
+    fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
+        let id = cx.create_entity();
+        let disabled = self.disabled;
+        let hovering = cx.is_hovered(id) && !disabled;
+        let focused = cx.is_focus_visible(id);
+        let theme = cx.use_theme();
+        let tokens = theme.size(self.size);
+        let (mut track, mut thumb) =
+            switch_colors(self.color, theme.clone(), self.checked, hovering);
+        if disabled {
+            let opacity = theme.disabled_opacity();
+            track = track.with_alpha(track.alpha * opacity);
+            thumb = thumb.with_alpha(thumb.alpha * opacity);
+        }
+        let ring = button_ring_color(self.color, theme.clone());
+        let thumb_size = tokens.font_size;
+        let target = if self.checked {
+            THUMB_INSET + thumb_size
+        } else {
+            THUMB_INSET
+        };
+        let label_start = self.label_placement == SwitchLabelPlacement::Start;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Switch")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Row)
+                        .align_items(bevy::ui::AlignItems::Center);
+                },
+                self.style.clone(),
+            ))
+            .style_dyn(
+                |(tokens, text, disabled), ss| {
+                    ss.column_gap(tokens.gap)
+                        .font_size(tokens.font_size)
+                        .color(text)
+                        .cursor(if disabled {
+                            CursorIcon::NotAllowed
+                        } else {
+                            CursorIcon::Pointer
+                        });
+                },
+                (tokens, theme.text(), disabled),
+            )
+            .insert_if(disabled, || Disabled)
+            .insert_dyn(
+                move |(checked, disabled, tab_index, on_change)| {
+                    let mut node = NodeBuilder::new(Role::Switch);
+                    node.set_toggled(if checked {
+                        Toggled::True
+                    } else {
+                        Toggled::False
+                    });
+                    if disabled {
+                        node.set_disabled();
+                    }
+
+                    let toggle = move |world: &mut World| {
+                        if let Some(on_change) = on_change {
+                            world.run_callback(on_change, !checked);
+                        }
+                    };
+
+                    (
+                        AccessibilityNode::from(node),
+                        TabIndex(if disabled { -1 } else { tab_index }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .unwrap();
+                                if !event.repeat
+                                    && (event.key_code == KeyCode::Space
+                                        || event.key_code == KeyCode::Enter)
+                                {
+                                    event.stop_propagation();
+                                    toggle(world);
+                                }
+                            }
+                        }),
+                        click_to_focus(id, toggle),
+                    )
+                },
+                (self.checked, disabled, self.tab_index, self.on_change),
+            )
+            .children((
+                Cond::new(label_start, self.label.clone(), ()),
+                Element::<NodeBundle>::new()
+                    .named("Switch::Track")
+                    .style_dyn(
+                        |(size, track, focused, ring), ss: &mut StyleBuilder| {
+                            ss.flex_shrink(0.)
+                                .width(size * 2. + THUMB_INSET * 2.)
+                                .height(size + THUMB_INSET * 2.)
+                                .border_radius(size / 2. + THUMB_INSET)
+                                .background_color(track);
+                            if focused {
+                                ss.outline_color(ring).outline_width(2).outline_offset(2);
+                            } else {
+                                ss.outline_color(Color::NONE);
+                            }
+                        },
+                        (thumb_size, track, focused, ring),
+                    )
+                    .children(
+                        // `left` is driven by `animate_switch_thumbs`, not the style.
+                        Element::<NodeBundle>::new()
+                            .named("Switch::Thumb")
+                            .style_dyn(
+                                |(size, color), ss: &mut StyleBuilder| {
+                                    ss.position(PositionType::Absolute)
+                                        .top(THUMB_INSET)
+                                        .width(size)
+                                        .height(size)
+                                        .border_radius(size / 2.)
+                                        .background_color(color);
+                                },
+                                (thumb_size, thumb),
+                            )
+                            .insert_dyn(|target| SwitchThumb { target }, target),
+                    ),
+                Cond::new(label_start, (), self.label.clone()),
+            ))
+    }
+}
+
+// Track: `control_fill` when on, bg-gray-200 dark:bg-gray-700 when off.
+// Thumb: bg-white dark:bg-gray-900, or a contrasting color on a track of the
+// same color.
+fn switch_colors(
+    color: ButtonColor,
+    theme: QuillUiTheme,
+    checked: bool,
+    is_hovering: bool,
+) -> (Srgba, Srgba) {
+    let dark = theme.mode == ThemeMode::DARK;
+    let track = if checked {
+        control_fill(color, &theme).0
+    } else {
+        match (dark, is_hovering) {
+            (true, false) => theme.colors.gray[7],
+            (true, true) => theme.colors.gray[6],
+            (false, false) => theme.colors.gray[2],
+            (false, true) => theme.colors.gray[3],
+        }
+    };
+    let thumb = if dark {
+        theme.colors.gray[9]
+    } else {
+        theme.colors.white
+    };
+    if thumb == track {
+        (track, theme.foreground_for(track))
+    } else {
+        (track, thumb)
+    }
+}
+
+pub(crate) fn animate_switch_thumbs(time: Res<Time>, mut query: Query<(&SwitchThumb, &mut Style)>) {
+    let step = (THUMB_SPEED * time.delta_seconds()).min(1.);
+    for (thumb, mut style) in query.iter_mut() {
+        let left = match style.left {
+            Val::Px(left) if (left - thumb.target).abs() > 0.5 => {
+                left + (thumb.target - left) * step
+            }
+            // Snap when close enough, or on first layout.
+            _ => thumb.target,
+        };
+        if style.left != Val::Px(left) {
+            style.left = Val::Px(left);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        a11y::Focus,
+        input::keyboard::Key,
+        prelude::{App, In, ResMut, Resource},
+    };
+    use bevy_quill::Cx;
+
+    use super::*;
+    use crate::ui::testing::{headless_app, named, spawn_view, tap_key};
+
+    #[test]
+    fn thumb_stands_out_on_every_track() {
+        for mode in [ThemeMode::DARK, ThemeMode::LIGHT] {
+            let theme = QuillUiTheme {
+                mode,
+                ..Default::default()
+            };
+            for color in [
+                ButtonColor::Primary,
+                ButtonColor::White,
+                ButtonColor::Black,
+                ButtonColor::Gray,
+            ] {
+                let (track, thumb) = switch_colors(color, theme.clone(), true, false);
+                assert_ne!(track, thumb, "{:?} {:?}", color, mode);
+            }
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Enabled(bool);
+
+    #[derive(Clone, PartialEq)]
+    struct SwitchView {
+        disabled: bool,
+    }
+
+    impl ViewTemplate for SwitchView {
+        type View = impl View;
+
+        fn create(&self, cx: &mut Cx) -> Self::View {
+            let checked = cx.use_resource::<Enabled>().0;
+            let on_change = cx.create_callback(|value: In<bool>, mut enabled: ResMut<Enabled>| {
+                enabled.0 = *value;
+            });
+
+            Switch::new()
+                .checked(checked)
+                .disabled(self.disabled)
+                .on_change(on_change)
+                .label("Wi-Fi")
+        }
+    }
+
+    fn focused_switch(disabled: bool) -> App {
+        let mut app = headless_app();
+        app.init_resource::<Enabled>();
+        spawn_view(&mut app, SwitchView { disabled });
+        let switch = named(&mut app, "Switch")[0];
+        app.world_mut().resource_mut::<Focus>().0 = Some(switch);
+        app
+    }
+
+    #[test]
+    fn space_and_enter_toggle_the_focused_switch() {
+        let mut app = focused_switch(false);
+
+        tap_key(&mut app, KeyCode::Space, Key::Space);
+        assert!(app.world().resource::<Enabled>().0);
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert!(!app.world().resource::<Enabled>().0);
+    }
+
+    #[test]
+    fn disabled_switch_ignores_keys() {
+        let mut app = focused_switch(true);
+
+        tap_key(&mut app, KeyCode::Space, Key::Space);
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert!(!app.world().resource::<Enabled>().0);
+    }
+}
@@ -0,0 +1,88 @@
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::{App, Camera2dBundle, DefaultPlugins, Entity, KeyCode, Name, PluginGroup, With},
+    render::{settings::WgpuSettings, RenderPlugin},
+    window::PrimaryWindow,
+    winit::WinitPlugin,
+};
+use bevy_mod_picking::DefaultPickingPlugins;
+use bevy_quill::{QuillPlugin, View};
+use bevy_quill_obsidian::ObsidianUiPlugin;
+
+use super::QuillUiPlugin;
+
+// An app with the full UI stack but no window backend or GPU, driven by
+// calling `update` by hand.
+pub(crate) fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        DefaultPlugins
+            .set(RenderPlugin {
+                render_creation: WgpuSettings {
+                    backends: None,
+                    ..Default::default()
+                }
+                .into(),
+                ..Default::default()
+            })
+            .disable::<WinitPlugin>()
+            .disable::<bevy::log::LogPlugin>(),
+        DefaultPickingPlugins,
+        QuillPlugin,
+        QuillUiPlugin {
+            sync_clear_color: false,
+            persistence: None,
+        },
+        ObsidianUiPlugin,
+    ));
+    app.world_mut().spawn(Camera2dBundle::default());
+    app
+}
+
+// Spawns `view` as a root and lets it build and lay out.
+pub(crate) fn spawn_view<V: View>(app: &mut App, view: V) {
+    app.world_mut().spawn(view.to_root());
+    settle(app);
+}
+
+pub(crate) fn settle(app: &mut App) {
+    for _ in 0..4 {
+        app.update();
+    }
+}
+
+// Entities named `name` (see `Element::named`), in spawn order.
+pub(crate) fn named(app: &mut App, name: &str) -> Vec<Entity> {
+    let mut entities: Vec<_> = app
+        .world_mut()
+        .query::<(Entity, &Name)>()
+        .iter(app.world())
+        .filter(|(_, n)| n.as_str() == name)
+        .map(|(entity, _)| entity)
+        .collect();
+    entities.sort();
+    entities
+}
+
+pub(crate) fn send_key(app: &mut App, key_code: KeyCode, logical_key: Key, state: ButtonState) {
+    let window = app
+        .world_mut()
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .single(app.world());
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state,
+        window,
+    });
+    app.update();
+}
+
+// Presses and releases a key.
+pub(crate) fn tap_key(app: &mut App, key_code: KeyCode, logical_key: Key) {
+    send_key(app, key_code, logical_key.clone(), ButtonState::Pressed);
+    send_key(app, key_code, logical_key, ButtonState::Released);
+}
@@ -0,0 +1,263 @@
+use std::ops::Range;
+
+use bevy::prelude::{Component, Resource};
+
+// Editable text with a caret and a selection anchor, both in chars.
+#[derive(Component, Default, Clone, Debug, PartialEq)]
+pub struct TextBuffer {
+    pub text: String,
+    pub caret: usize,
+    pub anchor: usize,
+}
+
+impl TextBuffer {
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let end = text.chars().count();
+        Self {
+            text,
+            caret: end,
+            anchor: end,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    // Replaces the text, keeping the caret where it was if it still fits.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+        let len = self.len();
+        self.caret = self.caret.min(len);
+        self.anchor = self.anchor.min(len);
+    }
+
+    pub fn selection(&self) -> Range<usize> {
+        self.caret.min(self.anchor)..self.caret.max(self.anchor)
+    }
+
+    pub fn has_selection(&self) -> bool {
+        self.caret != self.anchor
+    }
+
+    pub fn selected_text(&self) -> String {
+        let range = self.selection();
+        self.text[self.byte(range.start)..self.byte(range.end)].to_string()
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = 0;
+        self.caret = self.len();
+    }
+
+    pub fn insert(&mut self, input: &str) {
+        let range = self.selection();
+        let (start, end) = (self.byte(range.start), self.byte(range.end));
+        self.text.replace_range(start..end, input);
+        self.caret = range.start + input.chars().count();
+        self.anchor = self.caret;
+    }
+
+    // Inserts as much of `input` as fits in `max_chars`, counting the
+    // selection it replaces as free. Nothing happens when none of it fits.
+    pub fn insert_within(&mut self, input: &str, max_chars: Option<usize>) {
+        let Some(max_chars) = max_chars else {
+            return self.insert(input);
+        };
+
+        let room = max_chars.saturating_sub(self.len() - self.selection().len());
+        let clipped = match input.char_indices().nth(room) {
+            Some((end, _)) => &input[..end],
+            None => input,
+        };
+        if clipped.is_empty() && !input.is_empty() {
+            return;
+        }
+        self.insert(clipped);
+    }
+
+    pub fn backspace(&mut self) {
+        if !self.has_selection() && self.caret > 0 {
+            self.anchor = self.caret - 1;
+        }
+        self.insert("");
+    }
+
+    pub fn delete(&mut self) {
+        if !self.has_selection() && self.caret < self.len() {
+            self.anchor = self.caret + 1;
+        }
+        self.insert("");
+    }
+
+    pub fn move_to(&mut self, position: usize, extend: bool) {
+        self.caret = position.min(self.len());
+        if !extend {
+            self.anchor = self.caret;
+        }
+    }
+
+    pub fn move_left(&mut self, extend: bool) {
+        let position = if self.has_selection() && !extend {
+            self.selection().start
+        } else {
+            self.caret.saturating_sub(1)
+        };
+        self.move_to(position, extend);
+    }
+
+    pub fn move_right(&mut self, extend: bool) {
+        let position = if self.has_selection() && !extend {
+            self.selection().end
+        } else {
+            self.caret + 1
+        };
+        self.move_to(position, extend);
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.text.split('\n').count()
+    }
+
+    // Line and column of a char index.
+    pub fn line_col(&self, index: usize) -> (usize, usize) {
+        let mut line = 0;
+        let mut col = 0;
+        for c in self.text.chars().take(index) {
+            if c == '\n' {
+                line += 1;
+                col = 0;
+            } else {
+                col += 1;
+            }
+        }
+        (line, col)
+    }
+
+    // Char index of a line and column, clamping the column to the line length.
+    pub fn index_of(&self, line: usize, col: usize) -> usize {
+        let mut index = 0;
+        for (i, text) in self.text.split('\n').enumerate() {
+            let len = text.chars().count();
+            if i == line {
+                return index + col.min(len);
+            }
+            index += len + 1;
+        }
+        self.len()
+    }
+
+    // First and last char index of the line holding `index`.
+    pub fn line_bounds(&self, index: usize) -> (usize, usize) {
+        let (line, _) = self.line_col(index);
+        (self.index_of(line, 0), self.index_of(line, usize::MAX))
+    }
+
+    pub fn move_home(&mut self, extend: bool) {
+        let (start, _) = self.line_bounds(self.caret);
+        self.move_to(start, extend);
+    }
+
+    pub fn move_end(&mut self, extend: bool) {
+        let (_, end) = self.line_bounds(self.caret);
+        self.move_to(end, extend);
+    }
+
+    pub fn move_up(&mut self, extend: bool) {
+        let (line, col) = self.line_col(self.caret);
+        let position = if line == 0 {
+            0
+        } else {
+            self.index_of(line - 1, col)
+        };
+        self.move_to(position, extend);
+    }
+
+    pub fn move_down(&mut self, extend: bool) {
+        let (line, col) = self.line_col(self.caret);
+        let position = if line + 1 >= self.line_count() {
+            self.len()
+        } else {
+            self.index_of(line + 1, col)
+        };
+        self.move_to(position, extend);
+    }
+
+    // Per-line version of `segments`, for multi-line rendering.
+    pub fn line_segments(&self) -> Vec<LineSegments> {
+        let selection = self.selection();
+        let mut start = 0;
+
+        self.text
+            .split('\n')
+            .map(|line| {
+                let end = start + line.chars().count();
+                let byte = |index: usize| {
+                    line.char_indices()
+                        .nth(index)
+                        .map_or(line.len(), |(byte, _)| byte)
+                };
+                let sel_start = byte(selection.start.clamp(start, end) - start);
+                let sel_end = byte(selection.end.clamp(start, end) - start);
+                let caret = (start..=end).contains(&self.caret).then(|| {
+                    if self.caret == selection.start {
+                        CaretPlacement::BeforeSelection
+                    } else {
+                        CaretPlacement::AfterSelection
+                    }
+                });
+                start = end + 1;
+
+                LineSegments {
+                    before: line[..sel_start].to_string(),
+                    selected: line[sel_start..sel_end].to_string(),
+                    after: line[sel_end..].to_string(),
+                    caret,
+                }
+            })
+            .collect()
+    }
+
+    // Splits the text around the selection for rendering.
+    pub fn segments(&self) -> (String, String, String) {
+        let range = self.selection();
+        let (start, end) = (self.byte(range.start), self.byte(range.end));
+        (
+            self.text[..start].to_string(),
+            self.text[start..end].to_string(),
+            self.text[end..].to_string(),
+        )
+    }
+
+    pub(crate) fn byte(&self, index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(index)
+            .map_or(self.text.len(), |(byte, _)| byte)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CaretPlacement {
+    BeforeSelection,
+    AfterSelection,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineSegments {
+    pub before: String,
+    pub selected: String,
+    pub after: String,
+    pub caret: Option<CaretPlacement>,
+}
+
+// Clipboard for cut, copy and paste between the app's own text fields. It
+// lives inside the app: text copied in other programs can't be pasted here,
+// and text copied here doesn't reach them.
+#[derive(Resource, Default, Clone, Debug, PartialEq)]
+pub struct InternalClipboard(pub String);
@@ -0,0 +1,564 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    color::{Alpha, Srgba},
+    prelude::{
+        Children, Component, DetectChanges, DetectChangesMut, Entity, GlobalTransform, NodeBundle,
+        Query, Ref, Visibility, With, World,
+    },
+    ui::{Node, Val},
+    window::CursorIcon,
+};
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderFont, StyleBuilderLayout, StyleHandle, StyleTuple,
+};
+use bevy_quill::{Callback, Cond, Element, For, View, ViewTemplate};
+use bevy_quill_obsidian::{
+    controls::{Disabled, ScrollView},
+    cursor::StyleBuilderCursor,
+    focus::TabIndex,
+    hooks::UseIsFocus,
+    scrolling::ScrollArea,
+};
+
+use super::{
+    button_ring_color, click_to_focus, input_colors, ButtonColor, ButtonSize, CaretPlacement,
+    LineSegments, TextBuffer, TextInput, UseTheme,
+};
+
+// Entities inside a textarea that `measure_textareas` reads back after layout.
+#[derive(Component, Clone, Copy, PartialEq)]
+pub(crate) struct TextareaParts {
+    // Hidden one-line text, measured to get the font's line height.
+    probe: Entity,
+    content: Entity,
+    // Frames left to keep the caret scrolled into view after an edit.
+    follow_caret: u8,
+}
+
+// Sizes taken from the last layout.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub(crate) struct TextareaMetrics {
+    line_height: f32,
+    content_height: f32,
+}
+
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub(crate) struct TextareaCaret;
+
+#[derive(Clone, PartialEq)]
+pub struct Textarea {
+    pub value: String,
+    pub placeholder: String,
+    pub color: ButtonColor,
+    pub size: ButtonSize,
+    pub style: StyleHandle,
+    pub disabled: bool,
+    pub tab_index: i32,
+    pub rows: usize,
+    // Grow with the content from `rows` up to `max_rows`, then scroll.
+    pub autoresize: bool,
+    pub max_rows: usize,
+    pub max_length: Option<usize>,
+    pub show_count: bool,
+
+    pub on_change: Option<Callback<String>>,
+    pub on_submit: Option<Callback<String>>,
+}
+
+impl Default for Textarea {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            placeholder: String::new(),
+            color: ButtonColor::default(),
+            size: ButtonSize::default(),
+            style: StyleHandle::default(),
+            disabled: false,
+            tab_index: 0,
+            rows: 3,
+            autoresize: false,
+            max_rows: 10,
+            max_length: None,
+            show_count: false,
+            on_change: None,
+            on_submit: None,
+        }
+    }
+}
+
+impl Textarea {
+    pub fn new() -> Textarea {
+        Self::default()
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn color(mut self, color: ButtonColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    pub fn autoresize(mut self, max_rows: usize) -> Self {
+        self.autoresize = true;
+        self.max_rows = max_rows;
+        self
+    }
+
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn show_count(mut self, show_count: bool) -> Self {
+        self.show_count = show_count;
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<String>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn on_submit(mut self, callback: Callback<String>) -> Self {
+        self.on_submit = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Textarea {
+    type View = impl View;
+
+    fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
+        let id = cx.create_entity();
+        let disabled = self.disabled;
+        let focused = cx.is_focused(id) && !disabled;
+        let theme = cx.use_theme();
+        let tokens = theme.size(self.size);
+        let colors = input_colors(&theme);
+        let ring = button_ring_color(self.color, theme.clone());
+
+        cx.create_effect(
+            move |world: &mut World, value: String| match world.get_mut::<TextBuffer>(id) {
+                Some(mut buffer) => {
+                    if buffer.text != value {
+                        buffer.set_text(value);
+                    }
+                }
+                None => {
+                    world.entity_mut(id).insert(TextBuffer::new(value));
+                }
+            },
+            self.value.clone(),
+        );
+        let buffer = cx
+            .use_component::<TextBuffer>(id)
+            .cloned()
+            .unwrap_or_else(|| TextBuffer::new(self.value.clone()));
+
+        let probe = cx.create_entity();
+        let content = cx.create_entity();
+        let metrics = cx
+            .use_component::<TextareaMetrics>(id)
+            .copied()
+            .unwrap_or_default();
+        // Until the first layout, assume lines as tall as the font size.
+        let line_height = if metrics.line_height > 0. {
+            metrics.line_height
+        } else {
+            tokens.font_size
+        };
+        // Autoresize follows the laid-out content, so wrapped lines count too.
+        let min_height = self.rows as f32 * line_height;
+        let height = if self.autoresize {
+            let max_height = self.max_rows.max(self.rows) as f32 * line_height;
+            metrics.content_height.clamp(min_height, max_height)
+        } else {
+            min_height
+        };
+        let count = match self.max_length {
+            Some(max_length) => format!("{}/{}", buffer.len(), max_length),
+            None => buffer.len().to_string(),
+        };
+        let lines = buffer.line_segments();
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Textarea")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Column)
+                        .width(Val::Percent(100.));
+                },
+                self.style.clone(),
+            ))
+            .style_dyn(
+                |(colors, ring, focused, disabled, tokens, opacity), sb| {
+                    let dim = |c: Srgba| {
+                        if disabled {
+                            c.with_alpha(c.alpha * opacity)
+                        } else {
+                            c
+                        }
+                    };
+
+                    sb.background_color(dim(colors.bg))
+                        .color(dim(colors.text))
+                        .border(if focused { 2 } else { 1 })
+                        .border_color(dim(if focused { ring } else { colors.border }))
+                        .border_radius(tokens.radius)
+                        .font_size(tokens.font_size)
+                        .padding((tokens.padding_x, tokens.padding_y))
+                        .cursor(if disabled {
+                            CursorIcon::NotAllowed
+                        } else {
+                            CursorIcon::Text
+                        });
+                },
+                (
+                    colors,
+                    ring,
+                    focused,
+                    disabled,
+                    tokens,
+                    theme.disabled_opacity(),
+                ),
+            )
+            .insert_if(disabled, || Disabled)
+            .insert_dyn(
+                move |_| {
+                    (
+                        TextareaParts {
+                            probe,
+                            content,
+                            follow_caret: 0,
+                        },
+                        TextareaMetrics::default(),
+                    )
+                },
+                (),
+            )
+            .insert_dyn(
+                move |(disabled, tab_index, value, on_change, on_submit, max_length)| {
+                    let mut node = NodeBuilder::new(Role::MultilineTextInput);
+                    node.set_value(value);
+                    if disabled {
+                        node.set_disabled();
+                    }
+
+                    (
+                        AccessibilityNode::from(node),
+                        TabIndex(if disabled { -1 } else { tab_index }),
+                        TextInput {
+                            on_change,
+                            on_submit,
+                            multiline: true,
+                            max_length,
+                        },
+                        click_to_focus(id, |_: &mut World| {}),
+                    )
+                },
+                (
+                    disabled,
+                    self.tab_index,
+                    buffer.text.clone(),
+                    self.on_change,
+                    self.on_submit,
+                    self.max_length,
+                ),
+            )
+            .children((
+                Element::<NodeBundle>::for_entity(probe)
+                    .named("Textarea::Probe")
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.position(bevy::ui::PositionType::Absolute);
+                    })
+                    .insert_dyn(|_| Visibility::Hidden, ())
+                    .children("Ag"),
+                ScrollView::new()
+                    .style(move |ss: &mut StyleBuilder| {
+                        ss.height(height);
+                    })
+                    .scroll_enable_y(true)
+                    .children((
+                        Element::<NodeBundle>::for_entity(content)
+                            .named("Textarea::Content")
+                            .style(|ss: &mut StyleBuilder| {
+                                ss.display(bevy::ui::Display::Flex)
+                                    .flex_direction(bevy::ui::FlexDirection::Column);
+                            })
+                            .children(For::each(lines, move |line| TextareaLine {
+                                line: line.clone(),
+                                focused,
+                                line_height,
+                                caret_color: colors.text,
+                                selection_color: ring.with_alpha(0.35),
+                            })),
+                        Cond::new(
+                            buffer.is_empty(),
+                            Element::<NodeBundle>::new()
+                                .named("Textarea::Placeholder")
+                                .style_dyn(
+                                    |color, ss: &mut StyleBuilder| {
+                                        ss.position(bevy::ui::PositionType::Absolute)
+                                            .left(0)
+                                            .top(0)
+                                            .color(color);
+                                    },
+                                    colors.placeholder,
+                                )
+                                .children(self.placeholder.clone()),
+                            (),
+                        ),
+                    )),
+                Cond::new(
+                    self.show_count,
+                    Element::<NodeBundle>::new()
+                        .named("Textarea::Count")
+                        .style_dyn(
+                            |(color, font_size), ss: &mut StyleBuilder| {
+                                ss.align_self(bevy::ui::AlignSelf::End)
+                                    .font_size(font_size * 0.85)
+                                    .color(color);
+                            },
+                            (colors.placeholder, tokens.font_size),
+                        )
+                        .children(count),
+                    (),
+                ),
+            ))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct TextareaLine {
+    line: LineSegments,
+    focused: bool,
+    line_height: f32,
+    caret_color: Srgba,
+    selection_color: Srgba,
+}
+
+impl ViewTemplate for TextareaLine {
+    type View = impl View;
+
+    fn create(&self, _cx: &mut bevy_quill::Cx) -> Self::View {
+        let line_height = self.line_height;
+        let caret_color = self.caret_color;
+        let caret = move || {
+            Element::<NodeBundle>::new()
+                .named("Caret")
+                .style_dyn(
+                    |(color, height), ss: &mut StyleBuilder| {
+                        ss.width(1).height(height).background_color(color);
+                    },
+                    (caret_color, line_height),
+                )
+                .insert_dyn(|_| TextareaCaret, ())
+        };
+        let caret_before = self.focused && self.line.caret == Some(CaretPlacement::BeforeSelection);
+        let caret_after = self.focused && self.line.caret == Some(CaretPlacement::AfterSelection);
+
+        Element::<NodeBundle>::new()
+            .named("Textarea::Line")
+            .style_dyn(
+                |line_height, ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .flex_direction(bevy::ui::FlexDirection::Row)
+                        .flex_wrap(bevy::ui::FlexWrap::Wrap)
+                        .align_items(bevy::ui::AlignItems::Center)
+                        .min_height(line_height);
+                },
+                line_height,
+            )
+            .children((
+                self.line.before.clone(),
+                Cond::new(caret_before, caret(), ()),
+                Element::<NodeBundle>::new()
+                    .named("Textarea::Selection")
+                    .style_dyn(
+                        |color, ss: &mut StyleBuilder| {
+                            ss.background_color(color);
+                        },
+                        self.selection_color,
+                    )
+                    .children(self.line.selected.clone()),
+                Cond::new(caret_after, caret(), ()),
+                self.line.after.clone(),
+            ))
+    }
+}
+
+fn find_descendant(
+    entity: Entity,
+    children: &Query<&Children>,
+    matches: &impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    if matches(entity) {
+        return Some(entity);
+    }
+    children
+        .get(entity)
+        .ok()?
+        .iter()
+        .find_map(|child| find_descendant(*child, children, matches))
+}
+
+// Reads line and content heights back from layout, and keeps the caret in
+// view for a couple of frames after each edit (the view rebuilds a frame
+// after the buffer changes).
+pub(crate) fn measure_textareas(
+    mut textareas: Query<(
+        Entity,
+        Ref<TextBuffer>,
+        &mut TextareaParts,
+        &mut TextareaMetrics,
+    )>,
+    nodes: Query<(&Node, &GlobalTransform)>,
+    children: Query<&Children>,
+    carets: Query<(), With<TextareaCaret>>,
+    mut scroll_areas: Query<&mut ScrollArea>,
+) {
+    for (id, buffer, mut parts, mut metrics) in textareas.iter_mut() {
+        let height = |entity: Entity| nodes.get(entity).map_or(0., |(node, _)| node.size().y);
+        metrics.set_if_neq(TextareaMetrics {
+            line_height: height(parts.probe),
+            content_height: height(parts.content),
+        });
+
+        if buffer.is_changed() {
+            parts.follow_caret = 2;
+        }
+        if parts.follow_caret == 0 {
+            continue;
+        }
+        parts.follow_caret -= 1;
+
+        let Some(area) = find_descendant(id, &children, &|e| scroll_areas.contains(e)) else {
+            continue;
+        };
+        let Some(caret) = find_descendant(parts.content, &children, &|e| carets.contains(e)) else {
+            continue;
+        };
+        let (Ok((area_node, area_transform)), Ok((caret_node, caret_transform))) =
+            (nodes.get(area), nodes.get(caret))
+        else {
+            continue;
+        };
+
+        // Caret position relative to the top of the visible area.
+        let area_top = area_transform.translation().y - area_node.size().y / 2.;
+        let caret_top = caret_transform.translation().y - caret_node.size().y / 2. - area_top;
+        let caret_bottom = caret_top + caret_node.size().y;
+
+        let Ok(mut scroll) = scroll_areas.get_mut(area) else {
+            continue;
+        };
+        if caret_top < 0. {
+            scroll.scroll_top += caret_top;
+        } else if caret_bottom > area_node.size().y {
+            scroll.scroll_top += caret_bottom - area_node.size().y;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        a11y::Focus,
+        input::keyboard::Key,
+        prelude::{App, KeyCode},
+    };
+
+    use super::*;
+    use crate::ui::testing::{headless_app, named, settle, spawn_view, tap_key};
+
+    fn visible_height(app: &mut App) -> f32 {
+        app.world_mut()
+            .query_filtered::<&Node, With<ScrollArea>>()
+            .single(app.world())
+            .size()
+            .y
+    }
+
+    #[test]
+    fn autoresize_grows_with_lines_up_to_max_rows() {
+        let mut app = headless_app();
+        spawn_view(&mut app, Textarea::new().rows(1).autoresize(3));
+        let textarea = named(&mut app, "Textarea")[0];
+        app.world_mut().resource_mut::<Focus>().0 = Some(textarea);
+
+        tap_key(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+        settle(&mut app);
+        let line_height = app
+            .world()
+            .get::<TextareaMetrics>(textarea)
+            .unwrap()
+            .line_height;
+        assert!(line_height > 0.);
+
+        let mut heights = vec![visible_height(&mut app)];
+        for _ in 0..4 {
+            tap_key(&mut app, KeyCode::Enter, Key::Enter);
+            tap_key(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+            settle(&mut app);
+            heights.push(visible_height(&mut app));
+        }
+
+        // One row per line until `max_rows`, then it stops growing...
+        for (lines, height) in heights.iter().enumerate() {
+            let rows = (lines + 1).min(3) as f32;
+            assert!(
+                (height - rows * line_height).abs() < 1.,
+                "{} lines: {} (line height {})",
+                lines + 1,
+                height,
+                line_height
+            );
+        }
+
+        // ...and scrolls to keep the caret on the last line in view.
+        let scroll = app
+            .world_mut()
+            .query::<&ScrollArea>()
+            .single(app.world())
+            .scroll_top;
+        assert!(scroll > 0., "scroll_top {}", scroll);
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::{color::Srgba, prelude::Component};
+use bevy_quill::{Cx, IntoViewChild, View, ViewChild, ViewTemplate};
+
+use super::{QuillUiTheme, ThemeMode, ThemeTokens};
+
+#[derive(Default, Clone, PartialEq)]
+pub struct ThemeOverride {
+    pub mode: Option<ThemeMode>,
+    pub primary: Option<[Srgba; 11]>,
+    pub tokens: ThemeTokens,
+}
+
+impl QuillUiTheme {
+    pub fn with_override(&self, theme: &ThemeOverride) -> QuillUiTheme {
+        let mut result = self.clone();
+
+        if let Some(mode) = theme.mode {
+            result.mode = mode;
+        }
+
+        if let Some(primary) = theme.primary {
+            result.colors.primary = primary;
+        }
+
+        if theme.tokens.background.is_some() {
+            result.tokens.background = theme.tokens.background;
+        }
+
+        if theme.tokens.surface.is_some() {
+            result.tokens.surface = theme.tokens.surface;
+        }
+
+        if theme.tokens.text.is_some() {
+            result.tokens.text = theme.tokens.text;
+        }
+
+        if theme.tokens.disabled_opacity.is_some() {
+            result.tokens.disabled_opacity = theme.tokens.disabled_opacity;
+        }
+
+        result
+    }
+}
+
+// The resolved theme for a subtree, stored on the provider's owner entity.
+#[derive(Component, Clone, PartialEq)]
+pub struct ScopedTheme(pub QuillUiTheme);
+
+#[derive(Default, Clone, PartialEq)]
+pub struct ThemeProvider {
+    pub children: ViewChild,
+    pub theme: ThemeOverride,
+}
+
+impl ThemeProvider {
+    pub fn new() -> ThemeProvider {
+        Self::default()
+    }
+
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    pub fn mode(mut self, mode: ThemeMode) -> Self {
+        self.theme.mode = Some(mode);
+        self
+    }
+
+    pub fn primary(mut self, primary: [Srgba; 11]) -> Self {
+        self.theme.primary = Some(primary);
+        self
+    }
+
+    pub fn tokens(mut self, tokens: ThemeTokens) -> Self {
+        self.theme.tokens = tokens;
+        self
+    }
+}
+
+impl ViewTemplate for ThemeProvider {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        // Overrides are applied on top of the inherited theme, so nested
+        // providers stack on the outer ones.
+        let theme = cx.use_theme().with_override(&self.theme);
+
+        ThemeScope {
+            theme,
+            children: self.children.clone(),
+        }
+    }
+}
+
+// Holds the resolved theme on its own owner entity. Kept separate from
+// `ThemeProvider` so the provider never reads back the theme it provides.
+#[derive(Clone, PartialEq)]
+struct ThemeScope {
+    theme: QuillUiTheme,
+    children: ViewChild,
+}
+
+impl ViewTemplate for ThemeScope {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        cx.insert(ScopedTheme(self.theme.clone()));
+
+        self.children.clone()
+    }
+}
+
+pub trait UseTheme {
+    fn use_theme(&mut self) -> QuillUiTheme;
+}
+
+impl<'p, 'w> UseTheme for Cx<'p, 'w> {
+    fn use_theme(&mut self) -> QuillUiTheme {
+        let global = self.use_resource::<QuillUiTheme>().clone();
+
+        match self.use_inherited_component::<ScopedTheme>() {
+            Some(scoped) => scoped.0.clone(),
+            None => global,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::prelude::{Component, NodeBundle};
+use bevy_mod_stylebuilder::{StyleBuilder, StyleBuilderLayout, StyleHandle, StyleTuple};
+use bevy_quill::{Callback, Cx, Element, IntoViewChild, View, ViewChild, ViewTemplate};
+
+// Selection state shared with the buttons inside a `ToggleGroup`. Buttons take
+// part by setting a `value`.
+#[derive(Component, Default, Clone, PartialEq)]
+pub struct ToggleGroupContext {
+    pub selected: Option<String>,
+    pub on_change: Option<Callback<String>>,
+}
+
+#[derive(Default, Clone, PartialEq)]
+pub struct ToggleGroup {
+    pub children: ViewChild,
+    pub selected: Option<String>,
+    pub style: StyleHandle,
+    pub on_change: Option<Callback<String>>,
+}
+
+impl ToggleGroup {
+    pub fn new() -> ToggleGroup {
+        Self::default()
+    }
+
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    pub fn selected(mut self, selected: Option<impl Into<String>>) -> Self {
+        self.selected = selected.map(Into::into);
+        self
+    }
+
+    pub fn on_change(mut self, callback: Callback<String>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for ToggleGroup {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        cx.insert(ToggleGroupContext {
+            selected: self.selected.clone(),
+            on_change: self.on_change,
+        });
+
+        Element::<NodeBundle>::new()
+            .named("ToggleGroup")
+            .style((
+                |ss: &mut StyleBuilder| {
+                    ss.display(bevy::ui::Display::Flex)
+                        .align_items(bevy::ui::AlignItems::Center)
+                        .gap(10);
+                },
+                self.style.clone(),
+            ))
+            .children(self.children.clone())
+    }
+}
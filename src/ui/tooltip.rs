@@ -0,0 +1,349 @@
+use std::{f32::consts::FRAC_PI_4, time::Duration};
+
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    color::Srgba,
+    prelude::{Component, NodeBundle, Quat, Query, Res, Transform, World},
+    time::Time,
+    ui::{PositionType, Val, ZIndex},
+};
+use bevy_mod_picking::prelude::Pickable;
+use bevy_mod_stylebuilder::{
+    StyleBuilder, StyleBuilderBackground, StyleBuilderBorderColor, StyleBuilderBorderRadius,
+    StyleBuilderFont, StyleBuilderLayout, StyleHandle, StyleTuple,
+};
+use bevy_quill::{Cond, Cx, Element, IntoViewChild, View, ViewChild, ViewTemplate};
+use bevy_quill_obsidian::hooks::UseIsHover;
+
+use super::{
+    FloatAlign, FloatPlacement, FloatSide, Floating, FloatingResolved, ThemeMode, UseTheme,
+    FLOATING_Z,
+};
+
+const ARROW_SIZE: f32 = 8.0;
+
+// Hover state of a tooltip trigger, debounced by `update_tooltips`.
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct TooltipState {
+    pub hovered: bool,
+    pub open: bool,
+    changed_at: f32,
+    open_delay: f32,
+    close_delay: f32,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Tooltip {
+    pub children: ViewChild,
+    pub text: String,
+    // Keyboard shortcut shown next to the text, e.g. "Ctrl+S".
+    pub shortcut: Option<String>,
+    pub placement: FloatPlacement,
+    pub arrow: bool,
+    pub open_delay: Duration,
+    pub close_delay: Duration,
+    pub style: StyleHandle,
+}
+
+impl Default for Tooltip {
+    fn default() -> Self {
+        Self {
+            children: ViewChild::default(),
+            text: String::new(),
+            shortcut: None,
+            placement: FloatPlacement::new(FloatSide::Top, FloatAlign::Center),
+            arrow: false,
+            open_delay: Duration::from_millis(500),
+            close_delay: Duration::from_millis(100),
+            style: StyleHandle::default(),
+        }
+    }
+}
+
+impl Tooltip {
+    pub fn new() -> Tooltip {
+        Self::default()
+    }
+
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    pub fn placement(mut self, side: FloatSide, align: FloatAlign) -> Self {
+        self.placement = FloatPlacement::new(side, align);
+        self
+    }
+
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    pub fn open_delay(mut self, delay: Duration) -> Self {
+        self.open_delay = delay;
+        self
+    }
+
+    pub fn close_delay(mut self, delay: Duration) -> Self {
+        self.close_delay = delay;
+        self
+    }
+
+    // Style of the tooltip bubble.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for Tooltip {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let bubble = cx.create_entity();
+        let hovering = cx.is_hovered(id);
+        cx.create_effect(
+            move |world: &mut World, (hovered, open_delay, close_delay)| {
+                let now = world.resource::<Time>().elapsed_seconds();
+                let mut entity = world.entity_mut(id);
+                let mut state = entity.get::<TooltipState>().copied().unwrap_or_default();
+                if state.hovered != hovered {
+                    state.hovered = hovered;
+                    state.changed_at = now;
+                }
+                state.open_delay = open_delay;
+                state.close_delay = close_delay;
+                entity.insert(state);
+            },
+            (
+                hovering,
+                self.open_delay.as_secs_f32(),
+                self.close_delay.as_secs_f32(),
+            ),
+        );
+        let open = cx
+            .use_component::<TooltipState>(id)
+            .is_some_and(|state| state.open);
+        let side = cx
+            .use_component::<FloatingResolved>(bubble)
+            .map_or(self.placement.side, |resolved| resolved.0);
+
+        let theme = cx.use_theme();
+        let dark = theme.mode == ThemeMode::DARK;
+        // bg-white dark:bg-gray-900 ring-gray-200 dark:ring-gray-800
+        let (bg, border, kbd) = if dark {
+            (
+                theme.colors.gray[9],
+                theme.colors.gray[8],
+                theme.colors.gray[8],
+            )
+        } else {
+            (
+                theme.colors.white,
+                theme.colors.gray[2],
+                theme.colors.gray[1],
+            )
+        };
+        let gap = if self.arrow { ARROW_SIZE } else { 4. };
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Tooltip")
+            .style(|ss: &mut StyleBuilder| {
+                ss.display(bevy::ui::Display::Flex);
+            })
+            .children((
+                self.children.clone(),
+                Cond::new(
+                    open,
+                    Element::<NodeBundle>::for_entity(bubble)
+                        .named("Tooltip::Bubble")
+                        .style((
+                            |ss: &mut StyleBuilder| {
+                                ss.position(PositionType::Absolute)
+                                    .display(bevy::ui::Display::Flex)
+                                    .flex_direction(bevy::ui::FlexDirection::Row)
+                                    .align_items(bevy::ui::AlignItems::Center)
+                                    .gap(6)
+                                    .padding((8, 4))
+                                    .border(1)
+                                    .border_radius(4.0)
+                                    .font_size(12.);
+                            },
+                            self.style.clone(),
+                        ))
+                        .style_dyn(
+                            |(bg, border, text), ss: &mut StyleBuilder| {
+                                ss.background_color(bg).border_color(border).color(text);
+                            },
+                            (bg, border, theme.text()),
+                        )
+                        .insert_dyn(
+                            move |(text, placement, gap)| {
+                                let mut node = NodeBuilder::new(Role::Tooltip);
+                                node.set_name(text);
+                                (
+                                    AccessibilityNode::from(node),
+                                    Floating {
+                                        anchor: id,
+                                        placement,
+                                        gap,
+                                        match_width: false,
+                                    },
+                                    ZIndex::Global(FLOATING_Z),
+                                    // Hovering the bubble must not keep the trigger hovered.
+                                    Pickable::IGNORE,
+                                )
+                            },
+                            (self.text.clone(), self.placement, gap),
+                        )
+                        .children((
+                            self.text.clone(),
+                            Cond::new(
+                                self.shortcut.is_some(),
+                                Element::<NodeBundle>::new()
+                                    .named("Tooltip::Shortcut")
+                                    .style_dyn(
+                                        |bg, ss: &mut StyleBuilder| {
+                                            ss.padding((4, 0))
+                                                .border_radius(3.0)
+                                                .font_size(11.)
+                                                .background_color(bg);
+                                        },
+                                        kbd,
+                                    )
+                                    .children(self.shortcut.clone().unwrap_or_default()),
+                                (),
+                            ),
+                            Cond::new(
+                                self.arrow,
+                                TooltipArrow {
+                                    side,
+                                    align: self.placement.align,
+                                    bg,
+                                    border,
+                                },
+                                (),
+                            ),
+                        )),
+                    (),
+                ),
+            ))
+    }
+}
+
+// A square rotated into a diamond, half of which pokes out of the bubble
+// towards the trigger.
+#[derive(Clone, PartialEq)]
+struct TooltipArrow {
+    side: FloatSide,
+    align: FloatAlign,
+    bg: Srgba,
+    border: Srgba,
+}
+
+impl ViewTemplate for TooltipArrow {
+    type View = impl View;
+
+    fn create(&self, _cx: &mut Cx) -> Self::View {
+        Element::<NodeBundle>::new()
+            .named("Tooltip::Arrow")
+            .style_dyn(
+                |(side, align, bg, border), ss: &mut StyleBuilder| {
+                    let edge = Val::Px(-ARROW_SIZE / 2. - 1.);
+                    let along = |ss: &mut StyleBuilder, vertical: bool| {
+                        let (start, end) = match align {
+                            FloatAlign::Start => (Some(Val::Px(8.)), None),
+                            FloatAlign::Center => (Some(Val::Percent(50.)), None),
+                            FloatAlign::End => (None, Some(Val::Px(8.))),
+                        };
+                        let center = align == FloatAlign::Center;
+                        let half = -ARROW_SIZE / 2.;
+                        if vertical {
+                            if let Some(top) = start {
+                                ss.top(top);
+                            }
+                            if let Some(bottom) = end {
+                                ss.bottom(bottom);
+                            }
+                            if center {
+                                ss.margin_top(half);
+                            }
+                        } else {
+                            if let Some(left) = start {
+                                ss.left(left);
+                            }
+                            if let Some(right) = end {
+                                ss.right(right);
+                            }
+                            if center {
+                                ss.margin_left(half);
+                            }
+                        }
+                    };
+                    ss.position(PositionType::Absolute)
+                        .width(ARROW_SIZE)
+                        .height(ARROW_SIZE)
+                        .border(1)
+                        .border_color(border)
+                        .background_color(bg);
+                    // The bubble sits on `side` of the trigger, so the arrow
+                    // goes on the opposite edge of the bubble.
+                    match side {
+                        FloatSide::Top => {
+                            ss.bottom(edge);
+                            along(ss, false);
+                        }
+                        FloatSide::Bottom => {
+                            ss.top(edge);
+                            along(ss, false);
+                        }
+                        FloatSide::Left => {
+                            ss.right(edge);
+                            along(ss, true);
+                        }
+                        FloatSide::Right => {
+                            ss.left(edge);
+                            along(ss, true);
+                        }
+                    }
+                },
+                (self.side, self.align, self.bg, self.border),
+            )
+            .insert_dyn(
+                |_| Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_4)),
+                (),
+            )
+    }
+}
+
+pub(crate) fn update_tooltips(time: Res<Time>, mut query: Query<&mut TooltipState>) {
+    let now = time.elapsed_seconds();
+    for mut state in query.iter_mut() {
+        if state.open == state.hovered {
+            continue;
+        }
+        let delay = if state.hovered {
+            state.open_delay
+        } else {
+            state.close_delay
+        };
+        if now - state.changed_at >= delay {
+            state.open = state.hovered;
+        }
+    }
+}